@@ -0,0 +1,39 @@
+#![feature(test)]
+
+extern crate test;
+
+use p3d_si::slice::{add_slices, scale_slice};
+use p3d_si::Length;
+use test::{black_box, Bencher};
+
+const COUNT: usize = 1 << 16;
+
+fn lengths() -> Vec<Length> {
+    (0..COUNT).map(|i| Length::from(i as f64)).collect()
+}
+
+#[bench]
+fn add_slices_batched(bencher: &mut Bencher) {
+    let (a, b) = (lengths(), lengths());
+    let mut out = vec![Length::default(); COUNT];
+    bencher.iter(|| add_slices(black_box(&a), black_box(&b), black_box(&mut out)));
+}
+
+#[bench]
+fn add_slices_element_wise(bencher: &mut Bencher) {
+    let (a, b) = (lengths(), lengths());
+    let mut out = vec![Length::default(); COUNT];
+    bencher.iter(|| {
+        let out = black_box(&mut out);
+        for ((out, &a), &b) in out.iter_mut().zip(black_box(&a)).zip(black_box(&b)) {
+            *out = a + b;
+        }
+    });
+}
+
+#[bench]
+fn scale_slice_batched(bencher: &mut Bencher) {
+    let a = lengths();
+    let mut out = vec![Length::default(); COUNT];
+    bencher.iter(|| scale_slice(black_box(&a), 0.5, black_box(&mut out)));
+}
@@ -0,0 +1,43 @@
+use crate::{Float, Quantity, Unit};
+
+// Compares equal to any quantity within `tolerance` of `quantity`, so that
+// `assert_eq!(Approx::new(expected, tolerance), actual)` tolerates rounding
+// errors without changing the exact `PartialEq` of `Quantity` itself. There is
+// deliberately no `Quantity == Approx`: a second `PartialEq` impl on `Quantity`
+// would break inference of `assert_eq!(quantity, inferred)`.
+#[derive(Clone, Copy, Debug)]
+pub struct Approx<const UNIT: Unit, S = f64> {
+    pub quantity: Quantity<UNIT, S>,
+    pub tolerance: Quantity<UNIT, S>,
+}
+
+impl<const UNIT: Unit, S: Float> Approx<UNIT, S> {
+    pub fn new(quantity: Quantity<UNIT, S>, tolerance: Quantity<UNIT, S>) -> Self {
+        Self {
+            quantity,
+            tolerance,
+        }
+    }
+}
+
+impl<const UNIT: Unit, S: Float> PartialEq<Quantity<UNIT, S>> for Approx<UNIT, S> {
+    fn eq(&self, other: &Quantity<UNIT, S>) -> bool {
+        self.quantity.abs_diff_eq(*other, self.tolerance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn approx() {
+        let power = Energy::from(0.3) / Time::from(1.0);
+        let expected = Energy::from(0.1) / Time::from(1.0) * 3.0;
+        assert_ne!(power, expected);
+
+        let tolerance = Power::from(1e-12);
+        assert_eq!(Approx::new(expected, tolerance), power);
+        assert_ne!(Approx::new(expected, tolerance), Power::from(0.31));
+    }
+}
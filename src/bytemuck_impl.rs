@@ -0,0 +1,41 @@
+use crate::{Quantity, Unit};
+use bytemuck::{Pod, Zeroable};
+
+// Sound because `Quantity` is `repr(transparent)` over its storage type.
+unsafe impl<const UNIT: Unit, S: Zeroable> Zeroable for Quantity<UNIT, S> {}
+
+unsafe impl<const UNIT: Unit, S: Pod> Pod for Quantity<UNIT, S> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn layout() {
+        use std::mem::{align_of, size_of};
+        assert_eq!(size_of::<Length>(), size_of::<f64>());
+        assert_eq!(align_of::<Length>(), align_of::<f64>());
+    }
+
+    #[test]
+    fn cast_slice() {
+        let lengths = [Length::from(1.0), Length::from(2.0)];
+        let values: &[f64] = bytemuck::cast_slice(&lengths);
+        assert_eq!(values, &[1.0, 2.0]);
+
+        let bytes: &[u8] = bytemuck::cast_slice(&lengths);
+        assert_eq!(bytes.len(), 2 * std::mem::size_of::<f64>());
+
+        let velocities: &[Velocity<f32>] = bytemuck::cast_slice(&[3.0_f32, 4.0]);
+        assert_eq!(
+            velocities,
+            &[Velocity::from(3.0_f32), Velocity::from(4.0_f32)]
+        );
+    }
+
+    #[test]
+    fn zeroed() {
+        let force: Force = bytemuck::Zeroable::zeroed();
+        assert_eq!(force, Force::from(0.0));
+    }
+}
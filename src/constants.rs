@@ -0,0 +1,55 @@
+// Physical constants from CODATA 2018. Most of them are exact by definition of
+// the SI base units.
+use crate::*;
+
+pub const SPEED_OF_LIGHT: Velocity = Quantity::from_value(299_792_458.0);
+
+pub const STANDARD_GRAVITY: Acceleration = Quantity::from_value(9.80665);
+
+pub const STANDARD_ATMOSPHERE: Pressure = Quantity::from_value(101_325.0);
+
+pub const GRAVITATIONAL_CONSTANT: Quantity<
+    { Volume::<f64>::UNIT.sub(Mass::<f64>::UNIT.add(Time::<f64>::UNIT.mul(2))) },
+> = Quantity::from_value(6.674_30e-11);
+
+pub const PLANCK_CONSTANT: Quantity<{ Energy::<f64>::UNIT.add(Time::<f64>::UNIT) }> =
+    Quantity::from_value(6.626_070_15e-34);
+
+pub const BOLTZMANN_CONSTANT: HeatCapacity = Quantity::from_value(1.380_649e-23);
+
+pub const ELEMENTARY_CHARGE: Charge = Quantity::from_value(1.602_176_634e-19);
+
+pub const ELECTRON_MASS: Mass = Quantity::from_value(9.109_383_701_5e-31);
+
+pub const PROTON_MASS: Mass = Quantity::from_value(1.672_621_923_69e-27);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_gravity() {
+        assert!((STANDARD_GRAVITY.value() - 9.80665).abs() < 1e-12);
+        assert_eq!(Mass::from(2.0) * STANDARD_GRAVITY, Force::from(19.6133));
+    }
+
+    #[test]
+    fn speed_of_light() {
+        let length: Length = SPEED_OF_LIGHT * Time::from(2.0);
+        assert_eq!(length, Length::from(599_584_916.0));
+    }
+
+    #[test]
+    fn gravitational_constant() {
+        let mass = Mass::from(1.0);
+        let distance = Length::from(1.0);
+        let force: Force = GRAVITATIONAL_CONSTANT * mass * mass / (distance * distance);
+        assert_eq!(force.value(), GRAVITATIONAL_CONSTANT.value());
+    }
+
+    #[test]
+    fn planck_constant() {
+        let energy: Energy = PLANCK_CONSTANT * Frequency::from(1.0);
+        assert_eq!(energy.value(), PLANCK_CONSTANT.value());
+    }
+}
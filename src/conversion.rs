@@ -0,0 +1,143 @@
+use crate::*;
+
+// Conversions to and from common non-SI units. The factor is the size of one
+// such unit in SI base units, e.g. one foot is 0.3048 m.
+macro_rules! conversions {
+    ($($quantity: ident { $($from: ident, $to: ident => $factor: expr),* $(,)? })*) => {
+        $(
+            impl<S: Scalar> $quantity<S> {
+                $(
+                    pub fn $from(value: S) -> Self {
+                        Self::from_base_f64(value.to_f64() * $factor)
+                    }
+
+                    pub fn $to(self) -> S {
+                        S::from_f64(self.to_base_f64() / $factor)
+                    }
+                )*
+            }
+        )*
+    };
+}
+
+conversions!(
+    Length {
+        from_inches, to_inches => 0.0254,
+        from_feet, to_feet => 0.3048,
+        from_yards, to_yards => 0.9144,
+        from_miles, to_miles => 1609.344,
+        from_nautical_miles, to_nautical_miles => 1852.0,
+    }
+    Mass {
+        from_ounces, to_ounces => 0.028_349_523_125,
+        from_pounds, to_pounds => 0.453_592_37,
+        from_tonnes, to_tonnes => 1000.0,
+    }
+    Time {
+        from_minutes, to_minutes => 60.0,
+        from_hours, to_hours => 3600.0,
+        from_days, to_days => 86400.0,
+    }
+    Angle {
+        from_degrees, to_degrees => core::f64::consts::PI / 180.0,
+    }
+    Volume {
+        from_liters, to_liters => 1e-3,
+        from_us_gallons, to_us_gallons => 3.785_411_784e-3,
+    }
+    Velocity {
+        from_kilometers_per_hour, to_kilometers_per_hour => 1.0 / 3.6,
+        from_miles_per_hour, to_miles_per_hour => 0.447_04,
+        from_knots, to_knots => 1852.0 / 3600.0,
+    }
+    Pressure {
+        from_bar, to_bar => 1e5,
+        from_psi, to_psi => 6_894.757_293_168,
+    }
+    Energy {
+        from_calories, to_calories => 4.184,
+        from_kilowatt_hours, to_kilowatt_hours => 3.6e6,
+        from_electronvolts, to_electronvolts => 1.602_176_634e-19,
+    }
+    Power {
+        from_horsepower, to_horsepower => 745.699_871_582_270_2,
+    }
+);
+
+// Celsius and Fahrenheit are offset from kelvin, so these are only correct for
+// absolute temperatures. A temperature difference of 1 °C is 1 K, and one of
+// 1 °F is 5/9 K, which these functions would get wrong by the offset.
+impl<S: Scalar> Temperature<S> {
+    const ZERO_CELSIUS: f64 = 273.15;
+
+    pub fn from_celsius(value: S) -> Self {
+        Self::from_base_f64(value.to_f64() + Self::ZERO_CELSIUS)
+    }
+
+    pub fn to_celsius(self) -> S {
+        S::from_f64(self.to_base_f64() - Self::ZERO_CELSIUS)
+    }
+
+    pub fn from_fahrenheit(value: S) -> Self {
+        Self::from_celsius(S::from_f64((value.to_f64() - 32.0) * 5.0 / 9.0))
+    }
+
+    pub fn to_fahrenheit(self) -> S {
+        S::from_f64(self.to_celsius().to_f64() * 9.0 / 5.0 + 32.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn feet() {
+        assert_eq!(Length::from_feet(10.0), Length::from(3.048));
+        assert_eq!(Length::from(0.3048).to_feet(), 1.0);
+
+        let length = Length::from(1.234);
+        let round_trip = Length::from_feet(length.to_feet());
+        assert!(round_trip.abs_diff_eq(length, Length::from(1e-12)));
+    }
+
+    #[test]
+    fn pounds() {
+        assert_eq!(Mass::from_pounds(1.0), Mass::from(0.453_592_37));
+        assert!((Mass::from(1.0).to_pounds() - 2.204_622_621_8).abs() < 1e-9);
+
+        let mass = Mass::from(70.0);
+        let round_trip = Mass::from_pounds(mass.to_pounds());
+        assert!(round_trip.abs_diff_eq(mass, Mass::from(1e-12)));
+    }
+
+    #[test]
+    fn others() {
+        assert_eq!(Time::from_hours(2.0), Time::from(7200.0));
+        assert_eq!(
+            Velocity::from_kilometers_per_hour(36.0),
+            Velocity::from(10.0)
+        );
+        assert_eq!(Energy::from_kilowatt_hours(1.0), Energy::from(3.6e6));
+        assert!((Angle::from(std::f64::consts::PI).to_degrees() - 180.0).abs() < 1e-12);
+        assert_eq!(Length::<f32>::from_inches(100.0), Length::from(2.54_f32));
+    }
+
+    #[test]
+    fn temperature() {
+        assert_eq!(Temperature::from_celsius(0.0), Temperature::from(273.15));
+        assert_eq!(
+            Temperature::from_fahrenheit(32.0),
+            Temperature::from(273.15)
+        );
+        assert_eq!(Temperature::from(0.0).to_celsius(), -273.15);
+
+        let boiling = Temperature::from_celsius(100.0);
+        assert!((boiling.to_fahrenheit() - 212.0).abs() < 1e-9);
+        assert!((Temperature::from_fahrenheit(212.0).to_celsius() - 100.0).abs() < 1e-9);
+
+        let freezing = Temperature::from_fahrenheit(32.0);
+        assert!(freezing.to_celsius().abs() < 1e-9);
+        assert!((freezing.to_fahrenheit() - 32.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,159 @@
+// Quantity whose unit is only known at runtime, e.g. when parsed from user
+// input. Addition and subtraction check the units instead of the type system.
+use crate::{DimensionError, Quantity, Unit};
+use core::ops::{Div, Mul};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DynQuantity {
+    value: f64,
+    unit: Unit,
+}
+
+impl DynQuantity {
+    pub const fn new(value: f64, unit: Unit) -> Self {
+        Self { value, unit }
+    }
+
+    pub const fn value(self) -> f64 {
+        self.value
+    }
+
+    pub const fn unit(self) -> Unit {
+        self.unit
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, rhs: Self) -> Result<Self, DimensionError> {
+        DimensionError::check(self.unit, rhs.unit)?;
+        Ok(Self::new(self.value + rhs.value, self.unit))
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn sub(self, rhs: Self) -> Result<Self, DimensionError> {
+        DimensionError::check(self.unit, rhs.unit)?;
+        Ok(Self::new(self.value - rhs.value, self.unit))
+    }
+
+    // Like `*` and `/`, which panic if the exponents of the unit overflow, e.g.
+    // for units parsed from untrusted input.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let unit = self.unit.checked_add(rhs.unit)?;
+        Some(Self::new(self.value * rhs.value, unit))
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        let unit = self.unit.checked_sub(rhs.unit)?;
+        Some(Self::new(self.value / rhs.value, unit))
+    }
+}
+
+impl Mul for DynQuantity {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.value * rhs.value, self.unit.result_of_mul(rhs.unit))
+    }
+}
+
+impl Div for DynQuantity {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(self.value / rhs.value, self.unit.result_of_div(rhs.unit))
+    }
+}
+
+impl<const UNIT: Unit> From<Quantity<UNIT>> for DynQuantity {
+    fn from(quantity: Quantity<UNIT>) -> Self {
+        Self::new(quantity.0, UNIT)
+    }
+}
+
+impl<const UNIT: Unit> TryFrom<DynQuantity> for Quantity<UNIT> {
+    type Error = DimensionError;
+
+    fn try_from(quantity: DynQuantity) -> Result<Self, Self::Error> {
+        DimensionError::check(UNIT, quantity.unit)?;
+        Ok(Self(quantity.value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn add() {
+        let a = DynQuantity::from(Length::from(1.0));
+        let b = DynQuantity::from(Length::from(2.0));
+        assert_eq!(a.add(b), Ok(DynQuantity::from(Length::from(3.0))));
+        assert_eq!(b.sub(a), Ok(DynQuantity::from(Length::from(1.0))));
+    }
+
+    #[test]
+    fn mismatch() {
+        let length = DynQuantity::from(Length::from(1.0));
+        let time = DynQuantity::from(Time::from(2.0));
+        let error = length.add(time).unwrap_err();
+        assert_eq!(error.expected, length.unit());
+        assert_eq!(error.actual, time.unit());
+        assert!(length.sub(time).is_err());
+    }
+
+    #[test]
+    fn conversion() {
+        let mass = DynQuantity::from(Mass::from(2.0));
+        let acceleration = DynQuantity::from(Length::from(3.0))
+            / DynQuantity::from(Time::from(1.0))
+            / DynQuantity::from(Time::from(1.0));
+        let force: Force = (mass * acceleration).try_into().unwrap();
+        assert_eq!(force, Force::from(6.0));
+
+        let energy: Result<Energy, _> = (mass * acceleration).try_into();
+        assert!(energy.is_err());
+    }
+
+    #[test]
+    fn error() {
+        let velocity = DynQuantity::from(Velocity::from(1.0));
+        let error = Acceleration::try_from(velocity).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected m^1 kg^0 s^-2 A^0 K^0 rad^0 but found m^1 kg^0 s^-1 A^0 K^0 rad^0"
+        );
+
+        fn convert(quantity: DynQuantity) -> Result<Force, Box<dyn std::error::Error>> {
+            Ok(quantity.try_into()?)
+        }
+        assert!(convert(velocity).is_err());
+        assert_eq!(
+            convert(DynQuantity::from(Force::from(1.0))).unwrap(),
+            Force::from(1.0)
+        );
+    }
+
+    #[test]
+    fn checked() {
+        let length = DynQuantity::from(Length::from(2.0));
+        let time = DynQuantity::from(Time::from(4.0));
+        assert_eq!(
+            length.checked_mul(length),
+            Some(DynQuantity::from(Area::from(4.0)))
+        );
+        assert_eq!(
+            length.checked_div(time),
+            Some(DynQuantity::from(Velocity::from(0.5)))
+        );
+
+        let huge = DynQuantity::new(1.0, Unit::from_integers([i32::MAX, 0, 0, 0, 0, 0]));
+        assert_eq!(huge.checked_mul(length), None);
+        assert_eq!(
+            length.checked_div(huge),
+            Some(DynQuantity::new(
+                2.0,
+                Unit::from_integers([1 - i32::MAX, 0, 0, 0, 0, 0])
+            ))
+        );
+        assert_eq!(huge.checked_mul(huge), None);
+    }
+}
@@ -0,0 +1,188 @@
+// Fixed-point storage for deterministic simulations, e.g. `Length<Fixed>`. The
+// value is an `i64` with `FRAC_BITS` fractional bits, so integer arithmetic
+// gives bit-identical results on every platform and additions are exact and
+// associative. The resolution is 2^-FRAC_BITS and the range ±2^(63 - FRAC_BITS)
+// in base units: with the default of 32 bits about 2.3e-10 and ±2.1e9. Pick
+// fewer bits for large magnitudes. Products round towards negative infinity and
+// quotients towards zero. Sums out of range panic in debug builds like integer
+// arithmetic does, while products and quotients wrap. `Fixed` is not `Float`,
+// so there is no `sqrt` or trigonometry.
+use crate::Scalar;
+use core::ops::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed<const FRAC_BITS: u32 = 32>(i64);
+
+impl<const FRAC_BITS: u32> Fixed<FRAC_BITS> {
+    pub const RESOLUTION: f64 = 1.0 / (1u64 << FRAC_BITS) as f64;
+
+    pub const fn from_bits(bits: i64) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+}
+
+impl<const FRAC_BITS: u32> Scalar for Fixed<FRAC_BITS> {
+    const ZERO: Self = Self(0);
+    const ONE: Self = Self(1 << FRAC_BITS);
+
+    // Rounds to the nearest representable value and saturates at the ends of
+    // the range.
+    fn from_f64(value: f64) -> Self {
+        let scaled = value * (1u64 << FRAC_BITS) as f64;
+        let rounded = if scaled < 0.0 {
+            scaled - 0.5
+        } else {
+            scaled + 0.5
+        };
+        Self(rounded as i64)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.0 as f64 * Self::RESOLUTION
+    }
+}
+
+impl<const FRAC_BITS: u32> core::fmt::Display for Fixed<FRAC_BITS> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+// `Quantity` formats its value with `Debug` unless a precision is given, so this
+// prints the number rather than the raw bits too.
+impl<const FRAC_BITS: u32> core::fmt::Debug for Fixed<FRAC_BITS> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.to_f64(), f)
+    }
+}
+
+impl<const FRAC_BITS: u32> Add for Fixed<FRAC_BITS> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<const FRAC_BITS: u32> Sub for Fixed<FRAC_BITS> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<const FRAC_BITS: u32> Mul for Fixed<FRAC_BITS> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(((self.0 as i128 * rhs.0 as i128) >> FRAC_BITS) as i64)
+    }
+}
+
+impl<const FRAC_BITS: u32> Div for Fixed<FRAC_BITS> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self((self.0 as i128 * Self::ONE.0 as i128 / rhs.0 as i128) as i64)
+    }
+}
+
+impl<const FRAC_BITS: u32> Rem for Fixed<FRAC_BITS> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self(self.0 % rhs.0)
+    }
+}
+
+impl<const FRAC_BITS: u32> Neg for Fixed<FRAC_BITS> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+macro_rules! assign {
+    ($($trait: ident, $method: ident, $op: ident);*) => {
+        $(
+            impl<const FRAC_BITS: u32> $trait for Fixed<FRAC_BITS> {
+                fn $method(&mut self, rhs: Self) {
+                    *self = self.$op(rhs);
+                }
+            }
+        )*
+    };
+}
+
+assign!(
+    AddAssign, add_assign, add;
+    SubAssign, sub_assign, sub;
+    MulAssign, mul_assign, mul;
+    DivAssign, div_assign, div;
+    RemAssign, rem_assign, rem
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn fixed(value: f64) -> Fixed {
+        Fixed::from_f64(value)
+    }
+
+    #[test]
+    fn round_trip() {
+        for value in [0.0, 1.0, -1.0, 0.1, -2.75, 12345.6789, 1e-9] {
+            let length = Length::from(fixed(value));
+            assert!((length.value().to_f64() - value).abs() <= Fixed::<32>::RESOLUTION / 2.0);
+        }
+        assert_eq!(fixed(1.5).to_bits(), 3 << 31);
+        assert_eq!(Fixed::<32>::ONE.to_f64(), 1.0);
+        assert_eq!(Fixed::<8>::from_f64(0.1).to_bits(), 26);
+    }
+
+    #[test]
+    fn deterministic_sum() {
+        let steps: Vec<Length<Fixed>> = (0..1000)
+            .map(|i| Length::from(fixed(0.1 * i as f64 - 17.3)))
+            .collect();
+        let forward: Length<Fixed> = steps.iter().sum();
+        let backward: Length<Fixed> = steps.iter().rev().sum();
+        assert_eq!(forward, backward);
+        let expected: i64 = steps.iter().map(|step| step.value().to_bits()).sum();
+        assert_eq!(forward.value().to_bits(), expected);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let velocity: Velocity<Fixed> = Length::from(fixed(3.0)) / Time::from(fixed(2.0));
+        assert_eq!(velocity, Velocity::from(fixed(1.5)));
+        let area: Area<Fixed> = Length::from(fixed(-1.5)) * Length::from(fixed(2.5));
+        assert_eq!(area, Area::from(fixed(-3.75)));
+        assert_eq!(
+            Length::from(fixed(7.0)) % Length::from(fixed(2.5)),
+            Length::from(fixed(2.0))
+        );
+        assert_eq!(-Length::from(fixed(1.0)), Length::from(fixed(-1.0)));
+        assert_eq!(fixed(-1.0) / fixed(3.0), Fixed::from_bits(-(1 << 32) / 3));
+    }
+
+    #[test]
+    fn format() {
+        let length = Length::from(Fixed::<32>::from_f64(1.5));
+        assert_eq!(format!("{length}"), "1.5 m");
+        assert_eq!(format!("{length:.2}"), "1.50 m");
+        assert_eq!(
+            format!("{length:?}"),
+            "Quantity(1.5 m^1 kg^0 s^0 A^0 K^0 rad^0)"
+        );
+        assert_eq!(length.format_units_compact(), "1.5 m");
+        assert_eq!(format!("{:?}", fixed(-0.25)), "-0.25");
+    }
+}
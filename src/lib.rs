@@ -1,276 +1,2529 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(incomplete_features)]
 #![feature(generic_const_exprs, adt_const_params)]
 
-use std::ops::*;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod constants;
+pub mod prelude;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub mod serde_compact;
+pub mod slice;
+pub mod stats;
+
+mod approx;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impl;
+mod conversion;
+mod dynamic;
+#[cfg(feature = "fixed")]
+mod fixed;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl;
+mod named;
+#[cfg(feature = "num-traits")]
+mod num_traits_impl;
+mod ordered;
+#[cfg(feature = "alloc")]
+mod parse;
+mod prefix;
+#[cfg(feature = "proptest")]
+mod proptest_impl;
+#[cfg(feature = "rand")]
+mod rand_impl;
+mod scalar;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod si;
+#[cfg(feature = "uom")]
+mod uom_impl;
+mod vec3;
+
+pub use approx::Approx;
+pub use dynamic::DynQuantity;
+#[cfg(feature = "fixed")]
+pub use fixed::Fixed;
+pub use ordered::OrderedQuantity;
+#[cfg(feature = "alloc")]
+pub use parse::ParseQuantityError;
+pub use prefix::Prefix;
+#[cfg(feature = "rand")]
+pub use rand_impl::StandardQuantity;
+pub use scalar::{Float, Scalar};
+pub use si::Si;
+pub use vec3::Vec3;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::Write;
+use core::iter::Sum;
+use core::marker::ConstParamTy;
+use core::ops::*;
+
+// Exponents are rational so that roots of quantities stay representable, e.g.
+// sqrt(m^1) = m^(1/2). They are always kept in lowest terms with a positive
+// denominator, so structural equality used by const generics matches numeric
+// equality.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, ConstParamTy)]
+pub struct Ratio {
+    num: i32,
+    den: i32,
+}
+
+const fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a.abs()
+}
+
+// Exponents come from runtime input too, e.g. when parsing, so every operation
+// has a `checked_` variant which returns `None` on overflow instead of
+// panicking. The plain ones are meant for const contexts, where a panic is a
+// compile error.
+impl Ratio {
+    // Reduces in `i128` so that intermediate products can't overflow, `None` if
+    // the result doesn't fit into an `i32`.
+    const fn reduce(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+        let sign = if den < 0 { -1 } else { 1 };
+        let divisor = gcd(num, den);
+        let (num, den) = (sign * num / divisor, sign * den / divisor);
+        if num < i32::MIN as i128 || num > i32::MAX as i128 || den > i32::MAX as i128 {
+            return None;
+        }
+        Some(Self {
+            num: num as i32,
+            den: den as i32,
+        })
+    }
+
+    pub const fn checked_new(num: i32, den: i32) -> Option<Self> {
+        Self::reduce(num as i128, den as i128)
+    }
+
+    pub const fn new(num: i32, den: i32) -> Self {
+        assert!(den != 0, "ratio denominator must be non-zero");
+        Self::expect(Self::checked_new(num, den))
+    }
+
+    pub const fn int(value: i32) -> Self {
+        Self { num: value, den: 1 }
+    }
+
+    pub const fn to_integer(self) -> Option<i32> {
+        if self.den == 1 {
+            Some(self.num)
+        } else {
+            None
+        }
+    }
+
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (a, b) = (self.num as i128, self.den as i128);
+        let (c, d) = (rhs.num as i128, rhs.den as i128);
+        Self::reduce(a * d + c * b, b * d)
+    }
+
+    pub const fn add(self, rhs: Self) -> Self {
+        Self::expect(self.checked_add(rhs))
+    }
+
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (a, b) = (self.num as i128, self.den as i128);
+        let (c, d) = (rhs.num as i128, rhs.den as i128);
+        Self::reduce(a * d - c * b, b * d)
+    }
+
+    pub const fn sub(self, rhs: Self) -> Self {
+        Self::expect(self.checked_sub(rhs))
+    }
+
+    pub const fn checked_neg(self) -> Option<Self> {
+        self.checked_mul(-1)
+    }
+
+    pub const fn neg(self) -> Self {
+        Self::expect(self.checked_neg())
+    }
+
+    pub const fn checked_mul(self, n: i32) -> Option<Self> {
+        self.checked_scale(n, 1)
+    }
+
+    pub const fn mul(self, n: i32) -> Self {
+        Self::expect(self.checked_mul(n))
+    }
+
+    // Multiplies by `num / den`.
+    pub const fn checked_scale(self, num: i32, den: i32) -> Option<Self> {
+        Self::reduce(
+            self.num as i128 * num as i128,
+            self.den as i128 * den as i128,
+        )
+    }
+
+    pub const fn half(self) -> Self {
+        Self::expect(self.checked_scale(1, 2))
+    }
+
+    const fn expect(ratio: Option<Self>) -> Self {
+        match ratio {
+            Some(ratio) => ratio,
+            None => panic!("ratio out of range"),
+        }
+    }
+
+    // Accepts `n`, `n/d` and `(n/d)`, the forms produced by `Display`.
+    #[cfg(any(feature = "alloc", feature = "serde"))]
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let s = match s.strip_prefix('(') {
+            Some(inner) => inner.strip_suffix(')')?,
+            None => s,
+        };
+        let (num, den) = s.split_once('/').unwrap_or((s, "1"));
+        let num = num.trim().parse().ok()?;
+        let den = den.trim().parse().ok()?;
+        Self::checked_new(num, den)
+    }
+}
+
+impl core::fmt::Display for Ratio {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "({}/{})", self.num, self.den)
+        }
+    }
+}
 
 // Dimension of any quantity Q is written in the form of a dimensional product:
-//   dim Q = length^a, mass^b, time^c, ...
-// where the exponents a,b,c are signed integers.
+//   dim Q = length^a, mass^b, time^c, current^d, temperature^e, angle^f
+// where the exponents a,b,c,d,e,f are signed rationals.
+//
+// Angle is dimensionless in SI, but it is tracked as its own dimension so that
+// quantities which only differ by a per-radian factor get distinct types. For
+// example torque (N m rad^-1) and energy (N m) would otherwise be the same type.
+// Multiplying a torque by an angle yields energy.
+//
+// The exponents are stored as an array indexed by dimension, so the arithmetic
+// below is written once for any number of dimensions. `Units<N>` can describe
+// other dimension sets, while `Quantity` is tied to the SI set `Unit`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, ConstParamTy)]
+pub struct Units<const N: usize> {
+    exponents: [Ratio; N],
+}
+
+pub type Unit = Units<6>;
+
+impl<const N: usize> Units<N> {
+    const DIMENSIONLESS: Self = Self::new([Ratio::int(0); N]);
+
+    pub const fn new(exponents: [Ratio; N]) -> Self {
+        Self { exponents }
+    }
+
+    /// Integral exponents, e.g. for defining aliases outside this crate:
+    ///
+    /// ```
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs, adt_const_params)]
+    /// use p3d_si::prelude::*;
+    ///
+    /// // Rate of change of force.
+    /// type Yank = Quantity<{ Unit::from_integers([1, 1, -3, 0, 0, 0]) }>;
+    ///
+    /// let yank: Yank = 6.0.newtons() / 2.0.seconds();
+    /// assert_eq!(yank * 2.0.seconds(), 6.0.newtons());
+    /// ```
+    pub const fn from_integers(exponents: [i32; N]) -> Self {
+        let mut unit = Self::DIMENSIONLESS;
+        let mut i = 0;
+        while i < N {
+            unit.exponents[i] = Ratio::int(exponents[i]);
+            i += 1;
+        }
+        unit
+    }
+
+    pub const fn exponents(self) -> [Ratio; N] {
+        self.exponents
+    }
+
+    // Inverse of `from_integers`, `None` if any exponent is fractional.
+    pub const fn to_integers(self) -> Option<[i32; N]> {
+        let mut exponents = [0; N];
+        let mut i = 0;
+        while i < N {
+            match self.exponents[i].to_integer() {
+                Some(exponent) => exponents[i] = exponent,
+                None => return None,
+            }
+            i += 1;
+        }
+        Some(exponents)
+    }
+
+    // The dimension algebra in terms of the operations on the quantities, for
+    // checking units at runtime, e.g. in `DynQuantity`.
+    pub const fn is_compatible_add(self, other: Self) -> bool {
+        self.equals(other)
+    }
+
+    pub const fn result_of_mul(self, other: Self) -> Self {
+        self.add(other)
+    }
+
+    pub const fn result_of_div(self, other: Self) -> Self {
+        self.sub(other)
+    }
+
+    pub const fn is_dimensionless(self) -> bool {
+        self.equals(Self::DIMENSIONLESS)
+    }
+
+    pub const fn checked_add(mut self, rhs: Self) -> Option<Self> {
+        let mut i = 0;
+        while i < N {
+            match self.exponents[i].checked_add(rhs.exponents[i]) {
+                Some(exponent) => self.exponents[i] = exponent,
+                None => return None,
+            }
+            i += 1;
+        }
+        Some(self)
+    }
+
+    pub const fn add(self, rhs: Self) -> Self {
+        Self::expect(self.checked_add(rhs))
+    }
+
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match rhs.checked_neg() {
+            Some(rhs) => self.checked_add(rhs),
+            None => None,
+        }
+    }
+
+    pub const fn sub(self, rhs: Self) -> Self {
+        Self::expect(self.checked_sub(rhs))
+    }
+
+    pub const fn checked_neg(self) -> Option<Self> {
+        self.checked_mul(-1)
+    }
+
+    pub const fn neg(self) -> Self {
+        Self::expect(self.checked_neg())
+    }
+
+    pub const fn checked_mul(self, n: i32) -> Option<Self> {
+        self.checked_scale(n, 1)
+    }
+
+    pub const fn mul(self, n: i32) -> Self {
+        Self::expect(self.checked_mul(n))
+    }
+
+    // `PartialEq` isn't usable in const contexts, which `Quantity::cast_unit`
+    // needs.
+    pub const fn equals(self, rhs: Self) -> bool {
+        let mut i = 0;
+        while i < N {
+            let (a, b) = (self.exponents[i], rhs.exponents[i]);
+            if a.num != b.num || a.den != b.den {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    // Multiplies every exponent by `num / den`.
+    pub const fn checked_scale(mut self, num: i32, den: i32) -> Option<Self> {
+        let mut i = 0;
+        while i < N {
+            match self.exponents[i].checked_scale(num, den) {
+                Some(exponent) => self.exponents[i] = exponent,
+                None => return None,
+            }
+            i += 1;
+        }
+        Some(self)
+    }
+
+    pub const fn scale(self, num: i32, den: i32) -> Self {
+        Self::expect(self.checked_scale(num, den))
+    }
+
+    pub const fn half(mut self) -> Self {
+        let mut i = 0;
+        while i < N {
+            self.exponents[i] = self.exponents[i].half();
+            i += 1;
+        }
+        self
+    }
+
+    const fn expect(unit: Option<Self>) -> Self {
+        match unit {
+            Some(unit) => unit,
+            None => panic!("unit exponent out of range"),
+        }
+    }
+}
+
+impl Unit {
+    const TIME: Self = Time::<f64>::UNIT;
+
+    // Symbols of the base units, in the order of the exponents.
+    const SYMBOLS: [&'static str; 6] = ["m", "kg", "s", "A", "K", "rad"];
+
+    pub const fn length(self) -> Ratio {
+        self.exponents[0]
+    }
+
+    pub const fn mass(self) -> Ratio {
+        self.exponents[1]
+    }
+
+    pub const fn time(self) -> Ratio {
+        self.exponents[2]
+    }
+
+    pub const fn current(self) -> Ratio {
+        self.exponents[3]
+    }
+
+    pub const fn temperature(self) -> Ratio {
+        self.exponents[4]
+    }
+
+    pub const fn angle(self) -> Ratio {
+        self.exponents[5]
+    }
+
+    const fn terms(self) -> [(&'static str, Ratio); 6] {
+        let mut terms = [("", Ratio::int(0)); 6];
+        let mut i = 0;
+        while i < 6 {
+            terms[i] = (Self::SYMBOLS[i], self.exponents[i]);
+            i += 1;
+        }
+        terms
+    }
+
+    // Every exponent spelled out, e.g. `m^1 kg^0 s^-1 A^0 K^0 rad^0`.
+    fn write_exponents(self, w: &mut impl Write) -> core::fmt::Result {
+        let mut separator = "";
+        for (symbol, exponent) in self.terms() {
+            write!(w, "{separator}{symbol}^{exponent}")?;
+            separator = " ";
+        }
+        Ok(())
+    }
+}
+
+// Compile-time assertion usable in where-clauses, e.g.
+// `Assert<{ UNIT.equals(TARGET) }>: IsTrue`.
+pub struct Assert<const CHECK: bool>;
+
+pub trait IsTrue {}
+
+impl IsTrue for Assert<true> {}
+
+// Returned when two units were expected to be equal but are not.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub struct Unit {
-    length: i64,
-    mass: i64,
-    time: i64,
+pub struct DimensionError {
+    pub expected: Unit,
+    pub actual: Unit,
+}
+
+impl DimensionError {
+    pub(crate) fn check(expected: Unit, actual: Unit) -> Result<(), Self> {
+        if expected.is_compatible_add(actual) {
+            Ok(())
+        } else {
+            Err(Self { expected, actual })
+        }
+    }
+}
+
+// Spells out every exponent, like `format_units`, so the two units are easy to
+// compare term by term.
+impl core::fmt::Display for DimensionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("expected ")?;
+        self.expected.write_exponents(f)?;
+        f.write_str(" but found ")?;
+        self.actual.write_exponents(f)
+    }
+}
+
+impl core::error::Error for DimensionError {}
+
+// Renders only the non-zero exponents and drops `^1`, e.g. `m s^-1`. The
+// dimensionless unit renders as an empty string.
+impl core::fmt::Display for Unit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut separator = "";
+        for (symbol, exponent) in self.terms() {
+            if exponent == Ratio::int(0) {
+                continue;
+            }
+            write!(f, "{separator}{symbol}")?;
+            if exponent != Ratio::int(1) {
+                write!(f, "^{exponent}")?;
+            }
+            separator = " ";
+        }
+        Ok(())
+    }
+}
+
+// `UNIT` comes before the storage type `S` because only trailing generic
+// parameters can have defaults, and plain `f64` storage is the common case.
+//
+// The layout is guaranteed to match `S`, so a `Length` can be passed across an
+// `extern "C"` boundary as a `double` and `&[Length]` can be reinterpreted as
+// `&[f64]`.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Quantity<const UNIT: Unit, S = f64>(S);
+
+macro_rules! quantity {
+    ($name: ident, $length:literal, $mass: literal, $time: literal, $current: literal, $temperature: literal, $angle: literal) => {
+        pub type $name<S = f64> = Quantity<
+            { Unit::from_integers([$length, $mass, $time, $current, $temperature, $angle]) },
+            S,
+        >;
+    };
+}
+
+// Base units
+quantity!(Dimensionless, 0, 0, 0, 0, 0, 0);
+quantity!(Length, 1, 0, 0, 0, 0, 0);
+quantity!(Mass, 0, 1, 0, 0, 0, 0);
+quantity!(Time, 0, 0, 1, 0, 0, 0);
+quantity!(Current, 0, 0, 0, 1, 0, 0);
+quantity!(Temperature, 0, 0, 0, 0, 1, 0);
+quantity!(Angle, 0, 0, 0, 0, 0, 1);
+
+// Derived units
+quantity!(Area, 2, 0, 0, 0, 0, 0);
+quantity!(Wavenumber, -1, 0, 0, 0, 0, 0);
+quantity!(Volume, 3, 0, 0, 0, 0, 0);
+quantity!(Velocity, 1, 0, -1, 0, 0, 0);
+quantity!(Acceleration, 1, 0, -2, 0, 0, 0);
+quantity!(Jerk, 1, 0, -3, 0, 0, 0);
+quantity!(Snap, 1, 0, -4, 0, 0, 0);
+quantity!(Force, 1, 1, -2, 0, 0, 0);
+quantity!(Frequency, 0, 0, -1, 0, 0, 0);
+quantity!(Pressure, -1, 1, -2, 0, 0, 0);
+quantity!(SurfaceTension, 0, 1, -2, 0, 0, 0);
+quantity!(Energy, 2, 1, -2, 0, 0, 0);
+quantity!(SpecificEnergy, 2, 0, -2, 0, 0, 0);
+quantity!(Power, 2, 1, -3, 0, 0, 0);
+quantity!(Charge, 0, 0, 1, 1, 0, 0);
+quantity!(Voltage, 2, 1, -3, -1, 0, 0);
+quantity!(Resistance, 2, 1, -3, -2, 0, 0);
+quantity!(Capacitance, -2, -1, 4, 2, 0, 0);
+quantity!(HeatCapacity, 2, 1, -2, 0, -1, 0);
+quantity!(ThermalConductivity, 1, 1, -3, 0, -1, 0);
+quantity!(EntropyRate, 2, 1, -3, 0, -1, 0);
+quantity!(AngularVelocity, 0, 0, -1, 0, 0, 1);
+quantity!(Torque, 2, 1, -2, 0, 0, -1);
+quantity!(Momentum, 1, 1, -1, 0, 0, 0);
+quantity!(Action, 2, 1, -1, 0, 0, 0);
+quantity!(Density, -3, 1, 0, 0, 0, 0);
+quantity!(SpecificVolume, 3, -1, 0, 0, 0, 0);
+quantity!(MassFlowRate, 0, 1, -1, 0, 0, 0);
+quantity!(VolumetricFlowRate, 3, 0, -1, 0, 0, 0);
+// Pa s
+quantity!(DynamicViscosity, -1, 1, -1, 0, 0, 0);
+// m^2 s^-1, historically the stokes (1 St = 1e-4 m^2 s^-1)
+quantity!(KinematicViscosity, 2, 0, -1, 0, 0, 0);
+// Relative change in length or volume per kelvin.
+quantity!(ThermalExpansionCoefficient, 0, 0, 0, 0, -1, 0);
+quantity!(TemperatureGradient, -1, 0, 0, 0, 1, 0);
+
+// Impulse is the change in momentum, so the two share a type.
+pub type Impulse<S = f64> = Momentum<S>;
+
+// Mass diffusivity and thermal diffusivity share the unit of kinematic viscosity.
+pub type Diffusivity<S = f64> = KinematicViscosity<S>;
+
+// The curvature of a path is the reciprocal of its radius.
+pub type Curvature<S = f64> = Wavenumber<S>;
+
+// Force per length. Surface tension and the stiffness of a spring share the
+// dimensions and therefore the type, nothing keeps them apart.
+pub type SpringConstant<S = f64> = SurfaceTension<S>;
+pub type Stiffness<S = f64> = SurfaceTension<S>;
+
+// Temperatures are stored in kelvins without an offset, so a difference of two
+// temperatures is a temperature too. Only conversions from and to Celsius or
+// Fahrenheit have to tell them apart.
+pub type TemperatureDifference<S = f64> = Temperature<S>;
+
+impl<const UNIT: Unit, S: Scalar> Quantity<UNIT, S> {
+    pub(crate) const UNIT: Unit = UNIT;
+
+    pub const ZERO: Self = Self(S::ZERO);
+
+    pub const fn from_value(value: S) -> Self {
+        Self(value)
+    }
+
+    pub const fn value(self) -> S {
+        self.0
+    }
+
+    pub const fn unit(self) -> Unit {
+        UNIT
+    }
+
+    // Converts to another unit, which must be equal to `UNIT`. Useful where the
+    // units are only known to be equal once the const expressions are
+    // evaluated, e.g. between a generic `Quantity<{ A.add(B) }>` and a named
+    // alias.
+    pub fn cast_unit<const TARGET: Unit>(self) -> Quantity<TARGET, S>
+    where
+        Assert<{ UNIT.equals(TARGET) }>: IsTrue,
+    {
+        Quantity(self.0)
+    }
+
+    pub fn negate(&mut self) {
+        self.0 = -self.0;
+    }
+
+    // Applies `f` to the value and keeps the unit. It's up to the caller that
+    // this makes sense dimensionally, e.g. clamping does but squaring doesn't.
+    pub fn map(self, f: impl FnOnce(S) -> S) -> Self {
+        Self(f(self.0))
+    }
+
+    // Binary counterpart of `map`, with the same caveat.
+    pub fn zip_map(self, other: Self, f: impl FnOnce(S, S) -> S) -> Self {
+        Self(f(self.0, other.0))
+    }
+
+    // Single explicit Euler steps, e.g. `position + velocity.integrate(dt)`.
+    pub fn integrate(self, dt: Time<S>) -> Quantity<{ UNIT.add(Unit::TIME) }, S>
+    where
+        Quantity<{ UNIT.add(Unit::TIME) }, S>: Sized,
+    {
+        Quantity(self.0 * dt.0)
+    }
+
+    pub fn differentiate(self, dt: Time<S>) -> Quantity<{ UNIT.sub(Unit::TIME) }, S>
+    where
+        Quantity<{ UNIT.sub(Unit::TIME) }, S>: Sized,
+    {
+        Quantity(self.0 / dt.0)
+    }
+
+    // `start`, `start + step`, ... up to and including `stop`. Each item is
+    // computed as `start + step * i`, so rounding errors don't accumulate, but
+    // `stop` itself may still be missed by rounding. Empty if `step` is zero or
+    // points away from `stop`.
+    pub fn range_step(start: Self, stop: Self, step: Self) -> impl Iterator<Item = Self> {
+        (0u64..)
+            .map(move |i| Self(start.0 + step.0 * S::from_f64(i as f64)))
+            .take_while(move |value| {
+                if step.0 > S::ZERO {
+                    value.0 <= stop.0
+                } else if step.0 < S::ZERO {
+                    value.0 >= stop.0
+                } else {
+                    false
+                }
+            })
+    }
+
+    // Same as `* factor`, but typed as `Self` even where the product with a
+    // `Dimensionless` wouldn't normalize, e.g. in generic code.
+    pub fn scale_by(self, factor: Dimensionless<S>) -> Self {
+        Self(self.0 * factor.0)
+    }
+
+    // Same as `*`, named for geometry code where the sign carries meaning, e.g.
+    // the oriented area `a.x.signed_mul(b.y) - a.y.signed_mul(b.x)` of a 2D
+    // cross product.
+    pub fn signed_mul<const RHS: Unit>(
+        self,
+        rhs: Quantity<RHS, S>,
+    ) -> Quantity<{ UNIT.add(RHS) }, S>
+    where
+        Quantity<{ UNIT.add(RHS) }, S>: Sized,
+    {
+        Quantity(self.0 * rhs.0)
+    }
+
+    // The value in SI base units, i.e. meters, kilograms, seconds, amperes,
+    // kelvins and radians, which is always how quantities are stored. Unit
+    // conversions such as the prefixed and imperial constructors go through
+    // these.
+    pub fn to_base_f64(self) -> f64 {
+        self.0.to_f64()
+    }
+
+    pub fn from_base_f64(value: f64) -> Self {
+        Self(S::from_f64(value))
+    }
+
+    // Writes the value followed by every exponent, without allocating.
+    pub fn write_units<W: Write>(self, w: &mut W) -> core::fmt::Result {
+        self.write_units_precision(w, 1)
+    }
+
+    pub fn write_units_precision<W: Write>(self, w: &mut W, digits: usize) -> core::fmt::Result {
+        write!(w, "{:.*} ", digits, self.0)?;
+        UNIT.write_exponents(w)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn format_units(self) -> String {
+        self.format_units_precision(1)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn format_units_precision(self, digits: usize) -> String {
+        let mut s = String::new();
+        self.write_units_precision(&mut s, digits).unwrap();
+        s
+    }
+
+    // Like `format_units`, but leaves out zero exponents and `^1`, the same way
+    // `Display` does, e.g. `1.0 m kg s^-2`.
+    #[cfg(feature = "alloc")]
+    pub fn format_units_compact(self) -> String {
+        alloc::format!("{self:.1}")
+    }
+}
+
+// Trait methods can't be called in const contexts, so the operators are
+// mirrored as const fns for the primitive storage types. This allows building
+// tables of quantities at compile time.
+macro_rules! const_ops {
+    ($t: ty) => {
+        impl<const UNIT: Unit> Quantity<UNIT, $t> {
+            pub const fn const_add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+
+            pub const fn const_sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+
+            pub const fn const_neg(self) -> Self {
+                Self(-self.0)
+            }
+
+            pub const fn const_mul(self, rhs: $t) -> Self {
+                Self(self.0 * rhs)
+            }
+
+            pub const fn const_div(self, rhs: $t) -> Self {
+                Self(self.0 / rhs)
+            }
+        }
+    };
 }
 
-impl Unit {
-    pub const fn add(self, rhs: Self) -> Self {
-        Self {
-            length: self.length + rhs.length,
-            mass: self.mass + rhs.mass,
-            time: self.time + rhs.time,
+const_ops!(f32);
+const_ops!(f64);
+
+impl<const UNIT: Unit, S: Float> Quantity<UNIT, S> {
+    pub fn sqrt(self) -> Quantity<{ UNIT.half() }, S>
+    where
+        Quantity<{ UNIT.half() }, S>: Sized,
+    {
+        Quantity(self.0.sqrt())
+    }
+
+    pub fn powi<const N: i32>(self) -> Quantity<{ UNIT.mul(N) }, S>
+    where
+        Quantity<{ UNIT.mul(N) }, S>: Sized,
+    {
+        Quantity(self.0.powi(N))
+    }
+
+    // Raises to the rational power `N / D`. The exponent has to be known at
+    // compile time to compute the unit, and has to be rational for the unit
+    // exponents to stay rational. Only `Dimensionless` has `powf` with an
+    // arbitrary runtime exponent.
+    pub fn powr<const N: i32, const D: i32>(self) -> Quantity<{ UNIT.scale(N, D) }, S>
+    where
+        Quantity<{ UNIT.scale(N, D) }, S>: Sized,
+    {
+        Quantity(self.0.powf(S::from_f64(N as f64 / D as f64)))
+    }
+
+    pub fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    pub fn signum(self) -> Dimensionless<S> {
+        Quantity(self.0.signum())
+    }
+
+    pub fn is_sign_negative(self) -> bool {
+        self.0.is_sign_negative()
+    }
+
+    pub fn min(self, other: Self) -> Self {
+        Self(self.0.min(other.0))
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        Self(self.0.max(other.0))
+    }
+
+    pub fn clamp(self, lo: Self, hi: Self) -> Self {
+        Self(self.0.clamp(lo.0, hi.0))
+    }
+
+    pub fn abs_diff_eq(self, other: Self, epsilon: Self) -> bool {
+        (self.0 - other.0).abs() <= epsilon.0
+    }
+
+    // Same semantics as `approx::relative_eq!`: `epsilon` is an absolute
+    // tolerance for values near zero, `max_relative` is relative to the larger
+    // magnitude.
+    pub fn relative_eq(self, other: Self, epsilon: Self, max_relative: Dimensionless<S>) -> bool {
+        if self.0 == other.0 {
+            return true;
+        }
+        if self.0.is_infinite() || other.0.is_infinite() {
+            return false;
+        }
+        let diff = (self.0 - other.0).abs();
+        if diff <= epsilon.0 {
+            return true;
+        }
+        diff <= self.0.abs().max(other.0.abs()) * max_relative.0
+    }
+
+    pub fn round(self) -> Self {
+        Self(self.0.round())
+    }
+
+    pub fn floor(self) -> Self {
+        Self(self.0.floor())
+    }
+
+    pub fn ceil(self) -> Self {
+        Self(self.0.ceil())
+    }
+
+    pub fn trunc(self) -> Self {
+        Self(self.0.trunc())
+    }
+
+    pub fn is_nan(self) -> bool {
+        self.0.is_nan()
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+
+    pub fn is_infinite(self) -> bool {
+        self.0.is_infinite()
+    }
+
+    pub fn recip(self) -> Quantity<{ UNIT.neg() }, S>
+    where
+        Quantity<{ UNIT.neg() }, S>: Sized,
+    {
+        Quantity(self.0.recip())
+    }
+
+    // Computes `self * mul + add` with a single rounding error.
+    pub fn mul_add<const RHS: Unit>(
+        self,
+        mul: Quantity<RHS, S>,
+        add: Quantity<{ UNIT.add(RHS) }, S>,
+    ) -> Quantity<{ UNIT.add(RHS) }, S>
+    where
+        Quantity<{ UNIT.add(RHS) }, S>: Sized,
+    {
+        Quantity(self.0.mul_add(mul.0, add.0))
+    }
+
+    // Total order matching `f64::total_cmp`, which also orders NaN values.
+    pub fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+
+    pub fn sort_quantities(slice: &mut [Self]) {
+        slice.sort_unstable_by(Self::total_cmp);
+    }
+
+    // Unclamped, so `t` outside of `[0, 1]` extrapolates along the line.
+    pub fn lerp(self, other: Self, t: Dimensionless<S>) -> Self {
+        Self(self.0 + (other.0 - self.0) * t.0)
+    }
+
+    // Computes `sqrt(self^2 + other^2)` without intermediate overflow or
+    // underflow.
+    pub fn hypot(self, other: Self) -> Self {
+        Self(self.0.hypot(other.0))
+    }
+
+    // Magnitude of `self` with the sign of `sign`.
+    pub fn copysign(self, sign: Self) -> Self {
+        Self(self.0.copysign(sign.0))
+    }
+
+    pub fn fract(self) -> Self {
+        Self(self.0.fract())
+    }
+
+    // Returns `(self.trunc(), self.fract())`, which sum back to `self`.
+    pub fn split_int_fract(self) -> (Self, Self) {
+        (self.trunc(), self.fract())
+    }
+
+    // `None` if finite inputs overflow to infinity. Non-finite inputs are
+    // passed through like regular addition, since there is nothing to detect.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::checked(self, rhs, self + rhs)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::checked(self, rhs, self - rhs)
+    }
+
+    fn checked(lhs: Self, rhs: Self, result: Self) -> Option<Self> {
+        if lhs.is_finite() && rhs.is_finite() && !result.is_finite() {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    // Same as `clamp`, named for keeping a quantity within its physical range.
+    pub fn saturating_to(self, lo: Self, hi: Self) -> Self {
+        self.clamp(lo, hi)
+    }
+
+    // Floors the value at zero, e.g. for pressures or masses.
+    pub fn non_negative(self) -> Self {
+        self.max(Self(S::ZERO))
+    }
+
+    // Splits into `(self.abs(), self.signum())`, so that
+    // `magnitude * direction.value()` is `self` again.
+    pub fn magnitude_direction(self) -> (Self, Dimensionless<S>) {
+        (self.abs(), self.signum())
+    }
+
+    // Average of `self` and `other` that doesn't overflow for large values.
+    pub fn midpoint(self, other: Self) -> Self {
+        Self(self.0.midpoint(other.0))
+    }
+
+    // Limits the magnitude to the non-negative `max`, keeping the sign.
+    pub fn clamp_magnitude(self, max: Self) -> Self {
+        debug_assert!(max.0 >= S::ZERO, "maximum magnitude must be non-negative");
+        Self(self.0.clamp(-max.0, max.0))
+    }
+}
+
+impl<S: Float> Angle<S> {
+    pub fn sin(self) -> Dimensionless<S> {
+        Quantity(self.0.sin())
+    }
+
+    pub fn cos(self) -> Dimensionless<S> {
+        Quantity(self.0.cos())
+    }
+
+    pub fn tan(self) -> Dimensionless<S> {
+        Quantity(self.0.tan())
+    }
+}
+
+impl<S: Float> Dimensionless<S> {
+    pub fn asin(self) -> Angle<S> {
+        Quantity(self.0.asin())
+    }
+
+    pub fn acos(self) -> Angle<S> {
+        Quantity(self.0.acos())
+    }
+
+    pub fn atan(self) -> Angle<S> {
+        Quantity(self.0.atan())
+    }
+
+    pub fn exp(self) -> Self {
+        Self(self.0.exp())
+    }
+
+    pub fn ln(self) -> Self {
+        Self(self.0.ln())
+    }
+
+    pub fn log10(self) -> Self {
+        Self(self.0.log10())
+    }
+
+    pub fn powf(self, exp: Self) -> Self {
+        Self(self.0.powf(exp.0))
+    }
+}
+
+impl<S: Scalar> Dimensionless<S> {
+    pub fn from_percent(percent: S) -> Self {
+        Self(percent / S::from_f64(100.0))
+    }
+
+    pub fn to_percent(self) -> S {
+        self.0 * S::from_f64(100.0)
+    }
+
+    // E.g. `50.0%`.
+    #[cfg(feature = "alloc")]
+    pub fn format_percent(self) -> String {
+        alloc::format!("{:.1}%", self.to_percent())
+    }
+}
+
+impl<S: Float> Time<S> {
+    pub fn frequency(self) -> Frequency<S> {
+        Quantity(self.0.recip())
+    }
+}
+
+impl<S: Float> Frequency<S> {
+    pub fn period(self) -> Time<S> {
+        Quantity(self.0.recip())
+    }
+}
+
+// Angle of the point `(x, y)`. Both coordinates only need to share a unit since
+// their ratio is what matters.
+pub fn atan2<const UNIT: Unit, S: Float>(y: Quantity<UNIT, S>, x: Quantity<UNIT, S>) -> Angle<S> {
+    Quantity(y.0.atan2(x.0))
+}
+
+impl<const UNIT: Unit, S: Scalar> core::fmt::Display for Quantity<UNIT, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}", precision, self.0)?,
+            None => write!(f, "{:?}", self.0)?,
+        }
+        if UNIT != Unit::DIMENSIONLESS {
+            write!(f, " {UNIT}")?;
+        }
+        Ok(())
+    }
+}
+
+// Spells out every exponent so that failing `assert_eq!`s show the units.
+impl<const UNIT: Unit, S: core::fmt::Debug> core::fmt::Debug for Quantity<UNIT, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Quantity({:?} ", self.0)?;
+        UNIT.write_exponents(f)?;
+        f.write_str(")")
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> Default for Quantity<UNIT, S> {
+    fn default() -> Self {
+        Self(S::ZERO)
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> From<S> for Quantity<UNIT, S> {
+    fn from(value: S) -> Self {
+        Self(value)
+    }
+}
+
+// These discard the unit, so they are meant for system boundaries, e.g. when
+// passing values to APIs that take plain numbers.
+impl<const UNIT: Unit, S: Scalar> AsRef<S> for Quantity<UNIT, S> {
+    fn as_ref(&self) -> &S {
+        &self.0
+    }
+}
+
+macro_rules! into_scalar {
+    ($t: ty) => {
+        impl<const UNIT: Unit> From<Quantity<UNIT, $t>> for $t {
+            fn from(quantity: Quantity<UNIT, $t>) -> Self {
+                quantity.0
+            }
+        }
+    };
+}
+
+into_scalar!(f32);
+into_scalar!(f64);
+
+impl<const UNIT: Unit, S: Scalar> Add for Quantity<UNIT, S> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> Sub for Quantity<UNIT, S> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> Rem for Quantity<UNIT, S> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self(self.0 % rhs.0)
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> AddAssign for Quantity<UNIT, S> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> SubAssign for Quantity<UNIT, S> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> RemAssign for Quantity<UNIT, S> {
+    fn rem_assign(&mut self, rhs: Self) {
+        self.0 %= rhs.0;
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> MulAssign<S> for Quantity<UNIT, S> {
+    fn mul_assign(&mut self, rhs: S) {
+        self.0 *= rhs;
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> DivAssign<S> for Quantity<UNIT, S> {
+    fn div_assign(&mut self, rhs: S) {
+        self.0 /= rhs;
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> Neg for Quantity<UNIT, S> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> Neg for &Quantity<UNIT, S> {
+    type Output = Quantity<UNIT, S>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+// Multiplying or dividing by `Dimensionless` also goes through these impls. A
+// dedicated impl for it would overlap with them, and isn't needed: adding the
+// zero exponents evaluates to `LHS_UNIT`, so the output is exactly the type of
+// the left-hand side.
+impl<const LHS_UNIT: Unit, const RHS_UNIT: Unit, S: Scalar> Mul<Quantity<RHS_UNIT, S>>
+    for Quantity<LHS_UNIT, S>
+where
+    Quantity<{ LHS_UNIT.add(RHS_UNIT) }, S>: Sized,
+{
+    type Output = Quantity<{ LHS_UNIT.add(RHS_UNIT) }, S>;
+
+    fn mul(self, rhs: Quantity<RHS_UNIT, S>) -> Self::Output {
+        Quantity(self.0 * rhs.0)
+    }
+}
+
+impl<const LHS_UNIT: Unit, const RHS_UNIT: Unit, S: Scalar> Div<Quantity<RHS_UNIT, S>>
+    for Quantity<LHS_UNIT, S>
+where
+    Quantity<{ LHS_UNIT.sub(RHS_UNIT) }, S>: Sized,
+{
+    type Output = Quantity<{ LHS_UNIT.sub(RHS_UNIT) }, S>;
+
+    fn div(self, rhs: Quantity<RHS_UNIT, S>) -> Self::Output {
+        Quantity(self.0 / rhs.0)
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> Mul<S> for Quantity<UNIT, S> {
+    type Output = Self;
+
+    fn mul(self, rhs: S) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> Div<S> for Quantity<UNIT, S> {
+    type Output = Self;
+
+    fn div(self, rhs: S) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+// Reference variants of the operators between quantities, for generic code
+// with `for<'a> &'a T: Mul<&'a T>` style bounds. They copy and delegate to the
+// by-value impls.
+macro_rules! ref_same_unit {
+    ($($trait: ident, $method: ident);*) => {
+        $(
+            impl<const UNIT: Unit, S: Scalar> $trait<&Quantity<UNIT, S>> for Quantity<UNIT, S> {
+                type Output = Self;
+
+                fn $method(self, rhs: &Self) -> Self::Output {
+                    self.$method(*rhs)
+                }
+            }
+
+            impl<const UNIT: Unit, S: Scalar> $trait<Quantity<UNIT, S>> for &Quantity<UNIT, S> {
+                type Output = Quantity<UNIT, S>;
+
+                fn $method(self, rhs: Quantity<UNIT, S>) -> Self::Output {
+                    (*self).$method(rhs)
+                }
+            }
+
+            impl<const UNIT: Unit, S: Scalar> $trait<&Quantity<UNIT, S>> for &Quantity<UNIT, S> {
+                type Output = Quantity<UNIT, S>;
+
+                fn $method(self, rhs: &Quantity<UNIT, S>) -> Self::Output {
+                    (*self).$method(*rhs)
+                }
+            }
+        )*
+    };
+}
+
+ref_same_unit!(Add, add; Sub, sub);
+
+macro_rules! ref_any_unit {
+    ($($trait: ident, $method: ident, $op: ident);*) => {
+        $(
+            impl<const LHS_UNIT: Unit, const RHS_UNIT: Unit, S: Scalar>
+                $trait<&Quantity<RHS_UNIT, S>> for Quantity<LHS_UNIT, S>
+            where
+                Quantity<{ LHS_UNIT.$op(RHS_UNIT) }, S>: Sized,
+            {
+                type Output = Quantity<{ LHS_UNIT.$op(RHS_UNIT) }, S>;
+
+                fn $method(self, rhs: &Quantity<RHS_UNIT, S>) -> Self::Output {
+                    self.$method(*rhs)
+                }
+            }
+
+            impl<const LHS_UNIT: Unit, const RHS_UNIT: Unit, S: Scalar>
+                $trait<Quantity<RHS_UNIT, S>> for &Quantity<LHS_UNIT, S>
+            where
+                Quantity<{ LHS_UNIT.$op(RHS_UNIT) }, S>: Sized,
+            {
+                type Output = Quantity<{ LHS_UNIT.$op(RHS_UNIT) }, S>;
+
+                fn $method(self, rhs: Quantity<RHS_UNIT, S>) -> Self::Output {
+                    (*self).$method(rhs)
+                }
+            }
+
+            impl<const LHS_UNIT: Unit, const RHS_UNIT: Unit, S: Scalar>
+                $trait<&Quantity<RHS_UNIT, S>> for &Quantity<LHS_UNIT, S>
+            where
+                Quantity<{ LHS_UNIT.$op(RHS_UNIT) }, S>: Sized,
+            {
+                type Output = Quantity<{ LHS_UNIT.$op(RHS_UNIT) }, S>;
+
+                fn $method(self, rhs: &Quantity<RHS_UNIT, S>) -> Self::Output {
+                    (*self).$method(*rhs)
+                }
+            }
+        )*
+    };
+}
+
+ref_any_unit!(Mul, mul, add; Div, div, sub);
+
+// Scalars on the left-hand side can't be expressed generically over `S`
+// because of the orphan rules, so these are implemented per storage type.
+macro_rules! scalar_lhs {
+    ($t: ty) => {
+        impl<const UNIT: Unit> Mul<Quantity<UNIT, $t>> for $t {
+            type Output = Quantity<UNIT, $t>;
+
+            fn mul(self, rhs: Quantity<UNIT, $t>) -> Self::Output {
+                Quantity(self * rhs.0)
+            }
+        }
+
+        impl<const UNIT: Unit> Div<Quantity<UNIT, $t>> for $t
+        where
+            Quantity<{ UNIT.neg() }, $t>: Sized,
+        {
+            type Output = Quantity<{ UNIT.neg() }, $t>;
+
+            fn div(self, rhs: Quantity<UNIT, $t>) -> Self::Output {
+                Quantity(self / rhs.0)
+            }
+        }
+    };
+}
+
+scalar_lhs!(f32);
+scalar_lhs!(f64);
+
+impl<const UNIT: Unit, S: Scalar> Sum for Quantity<UNIT, S> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a, const UNIT: Unit, S: Scalar> Sum<&'a Quantity<UNIT, S>> for Quantity<UNIT, S> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+// Collecting sums, e.g. `let total: Force = forces.into_iter().collect();`.
+impl<const UNIT: Unit, S: Scalar> FromIterator<Self> for Quantity<UNIT, S> {
+    fn from_iter<I: IntoIterator<Item = Self>>(iter: I) -> Self {
+        iter.into_iter().sum()
+    }
+}
+
+// Asserts that two quantities of the same unit are within `epsilon`, e.g.
+// `assert_quantity_eq!(force, Force::from(9.8), Force::from(1e-9))`. Needs a
+// `Float` storage type and the `alloc` feature for the failure message.
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! assert_quantity_eq {
+    ($left: expr, $right: expr, $epsilon: expr $(,)?) => {
+        match ($left, $right, $epsilon) {
+            (left, right, epsilon) => {
+                if !left.abs_diff_eq(right, epsilon) {
+                    panic!(
+                        "assertion `left ≈ right` failed\n   left: {}\n  right: {}\nepsilon: {}",
+                        $crate::Quantity::format_units(left),
+                        $crate::Quantity::format_units(right),
+                        $crate::Quantity::format_units(epsilon),
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<f64>(), std::mem::size_of::<Length>());
+    }
+
+    #[test]
+    fn align_of() {
+        assert_eq!(std::mem::align_of::<f64>(), std::mem::align_of::<Length>());
+        assert_eq!(
+            std::mem::align_of::<f32>(),
+            std::mem::align_of::<Length<f32>>()
+        );
+    }
+
+    #[test]
+    fn transmute_slice() {
+        let lengths = [Length::from(1.0), Length::from(2.0)];
+        // SAFETY: `Quantity` is `repr(transparent)` over `f64`.
+        let values: &[f64] = unsafe { std::mem::transmute(&lengths[..]) };
+        assert_eq!(values, &[1.0, 2.0]);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let dimensionless = Dimensionless::from(1.0);
+        assert_eq!(dimensionless + dimensionless, Dimensionless::from(2.0));
+        assert_eq!(dimensionless - dimensionless, Dimensionless::from(0.0));
+
+        let mut dimensionless = Dimensionless::from(0.0);
+        dimensionless += Dimensionless::from(1.0);
+        assert_eq!(dimensionless, Dimensionless::from(1.0));
+
+        let mut dimensionless = Dimensionless::from(0.0);
+        dimensionless -= Dimensionless::from(1.0);
+        assert_eq!(dimensionless, Dimensionless::from(-1.0));
+
+        let dimensionless = Dimensionless::from(1.0);
+        assert_eq!(-dimensionless, Dimensionless::from(-1.0));
+    }
+
+    #[test]
+    fn scalar() {
+        assert_eq!(Length::from(3.0) * 2.0, Length::from(6.0));
+        assert_eq!(2.0 * Length::from(3.0), Length::from(6.0));
+        assert_eq!(Area::from(10.0) / 2.0, Area::from(5.0));
+
+        let mut velocity = Velocity::from(4.0);
+        velocity *= 0.5;
+        assert_eq!(velocity, Velocity::from(2.0));
+
+        let mut velocity = Velocity::from(4.0);
+        velocity /= 2.0;
+        assert_eq!(velocity, Velocity::from(2.0));
+    }
+
+    #[test]
+    fn value() {
+        assert_eq!(Length::from(2.5).value(), 2.5);
+        assert_eq!(Length::from_value(2.5), Length::from(2.5));
+
+        const LENGTH: Length = Length::from_value(2.5);
+        assert_eq!(LENGTH.value(), 2.5);
+    }
+
+    #[test]
+    fn formatting() {
+        let length = Length::from(1.0);
+        let format = length.format_units();
+        assert_eq!("1.0 m^1 kg^0 s^0 A^0 K^0 rad^0", format);
+
+        let root = Length::from(4.0).sqrt();
+        let format = root.format_units();
+        assert_eq!("2.0 m^(1/2) kg^0 s^0 A^0 K^0 rad^0", format);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("1.0", format!("{}", Dimensionless::from(1.0)));
+        assert_eq!("1.0 m", format!("{}", Length::from(1.0)));
+        assert_eq!("1.0 m s^-1", format!("{}", Velocity::from(1.0)));
+        assert_eq!("1.0 m kg s^-2", format!("{}", Force::from(1.0)));
+        assert_eq!("2.0 m^(1/2)", format!("{}", Length::from(4.0).sqrt()));
+        assert_eq!("1.500 m", format!("{:.3}", Length::from(1.5)));
+    }
+
+    #[test]
+    fn ratio() {
+        assert_eq!(Ratio::new(2, 4), Ratio::new(1, 2));
+        assert_eq!(Ratio::new(1, -2), Ratio::new(-1, 2));
+        assert_eq!(Ratio::new(0, 5), Ratio::int(0));
+        assert_eq!(Ratio::new(1, 2).add(Ratio::new(1, 2)), Ratio::int(1));
+        assert_eq!(Ratio::int(1).half().half(), Ratio::new(1, 4));
+    }
+
+    #[test]
+    fn checked_ratio() {
+        let max = Ratio::int(i32::MAX);
+        let min = Ratio::int(i32::MIN);
+        assert_eq!(Ratio::checked_new(i32::MIN, -1), None);
+        assert_eq!(Ratio::checked_new(1, 0), None);
+        assert_eq!(Ratio::checked_new(i32::MIN, i32::MIN), Some(Ratio::int(1)));
+        assert_eq!(max.checked_add(Ratio::int(1)), None);
+        assert_eq!(min.checked_sub(Ratio::int(1)), None);
+        assert_eq!(min.checked_neg(), None);
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(Ratio::new(1, 65536).checked_add(Ratio::new(1, 65537)), None);
+        assert_eq!(Ratio::new(1, 65536).checked_scale(1, 65536), None);
+        assert_eq!(max.checked_add(min), Some(Ratio::int(-1)));
+        assert_eq!(max.checked_neg(), Some(Ratio::int(-i32::MAX)));
+    }
+
+    #[test]
+    fn checked_unit() {
+        let length = Length::<f64>::UNIT;
+        let huge = Unit::from_integers([i32::MAX, 0, 0, 0, 0, 0]);
+        assert_eq!(huge.checked_add(length), None);
+        assert_eq!(huge.checked_mul(2), None);
+        assert_eq!(huge.checked_scale(3, 2), None);
+        assert_eq!(
+            Unit::from_integers([i32::MIN, 0, 0, 0, 0, 0]).checked_neg(),
+            None
+        );
+        assert_eq!(length.checked_sub(huge.neg()), None);
+        assert_eq!(length.checked_sub(length), Some(Dimensionless::<f64>::UNIT));
+        assert_eq!(length.checked_scale(1, 2), Some(length.half()));
+    }
+
+    #[test]
+    #[should_panic(expected = "unit exponent out of range")]
+    fn unit_overflow() {
+        Unit::from_integers([i32::MAX, 0, 0, 0, 0, 0]).mul(2);
+    }
+
+    #[test]
+    fn sqrt() {
+        let area = Area::from(9.0);
+        assert_eq!(area.sqrt(), Length::from(3.0));
+
+        let length = Length::from(4.0);
+        assert_eq!(length.sqrt() * length.sqrt(), length);
+    }
+
+    #[test]
+    fn powi() {
+        let length = Length::from(2.0);
+        assert_eq!(length.powi::<3>(), Volume::from(8.0));
+        assert_eq!(length.powi::<0>(), Dimensionless::from(1.0));
+
+        let time = Time::from(2.0);
+        assert_eq!(time.powi::<-1>(), Frequency::from(0.5));
+    }
+
+    #[test]
+    fn length() {
+        let length = Length::from(1.0);
+        let dimensionless = length / length;
+        assert_eq!(dimensionless, Dimensionless::from(1.0));
+    }
+
+    #[test]
+    fn area() {
+        let length = Length::from(1.0);
+        let area = length * length;
+        assert_eq!(area, Area::from(1.0));
+    }
+
+    #[test]
+    fn volume() {
+        let length = Length::from(1.0);
+        let volume = length * length * length;
+        assert_eq!(volume, Volume::from(1.0));
+    }
+
+    #[test]
+    fn velocity() {
+        let length = Length::from(1.0);
+        let time = Time::from(1.0);
+        let velocity = length / time;
+        assert_eq!(velocity, Velocity::from(1.0));
+    }
+
+    #[test]
+    fn acceleration() {
+        let length = Length::from(1.0);
+        let time = Time::from(1.0);
+        let acceleration = length / (time * time);
+        assert_eq!(acceleration, Acceleration::from(1.0));
+    }
+
+    #[test]
+    fn force() {
+        let length = Length::from(1.0);
+        let mass = Mass::from(1.0);
+        let time = Time::from(1.0);
+        let acceleration = length / (time * time);
+        let force = mass * acceleration;
+        assert_eq!(force, Force::from(1.0));
+    }
+
+    #[test]
+    fn frequency() {
+        let time = Time::from(1.0);
+        let frequency = 1.0 / time;
+        assert_eq!(frequency, Frequency::from(1.0));
+    }
+
+    #[test]
+    fn pressure() {
+        let length = Length::from(1.0);
+        let mass = Mass::from(1.0);
+        let time = Time::from(1.0);
+        let pressure = (1.0 / length) * mass / (time * time);
+        assert_eq!(pressure, Pressure::from(1.0));
+    }
+
+    #[test]
+    fn energy() {
+        let length = Length::from(1.0);
+        let mass = Mass::from(1.0);
+        let time = Time::from(1.0);
+        let energy = (length * length) * mass / (time * time);
+        assert_eq!(energy, Energy::from(1.0));
+    }
+
+    #[test]
+    fn power() {
+        let length = Length::from(1.0);
+        let mass = Mass::from(1.0);
+        let time = Time::from(1.0);
+        let energy = (length * length) * mass / (time * time);
+        let power = energy / time;
+        assert_eq!(power, Power::from(1.0));
+    }
+
+    #[test]
+    fn charge() {
+        let current = Current::from(2.0);
+        let time = Time::from(3.0);
+        assert_eq!(current * time, Charge::from(6.0));
+    }
+
+    #[test]
+    fn voltage() {
+        let power = Power::from(10.0);
+        let current = Current::from(2.0);
+        assert_eq!(power / current, Voltage::from(5.0));
+    }
+
+    #[test]
+    fn resistance() {
+        let voltage = Voltage::from(10.0);
+        let current = Current::from(2.0);
+        assert_eq!(voltage / current, Resistance::from(5.0));
+    }
+
+    #[test]
+    fn capacitance() {
+        let charge = Charge::from(6.0);
+        let voltage = Voltage::from(2.0);
+        assert_eq!(charge / voltage, Capacitance::from(3.0));
+    }
+
+    #[test]
+    fn entropy() {
+        // Entropy has the same dimensions as heat capacity.
+        let energy = Energy::from(100.0);
+        let temperature = Temperature::from(50.0);
+        assert_eq!(energy / temperature, HeatCapacity::from(2.0));
+    }
+
+    #[test]
+    fn entropy_rate() {
+        let power = Power::from(100.0);
+        let temperature = Temperature::from(50.0);
+        assert_eq!(power / temperature, EntropyRate::from(2.0));
+    }
+
+    #[test]
+    fn thermal_conductivity() {
+        let power = Power::from(100.0);
+        let length = Length::from(2.0);
+        let temperature = Temperature::from(10.0);
+        let conductivity = power / (length * temperature);
+        assert_eq!(conductivity, ThermalConductivity::from(5.0));
+    }
+
+    #[test]
+    fn sign() {
+        let force = Force::from(-2.0);
+        assert_eq!(force.abs(), Force::from(2.0));
+        assert_eq!(force.signum(), Dimensionless::from(-1.0));
+        assert!(force.is_sign_negative());
+        assert!(!force.abs().is_sign_negative());
+    }
+
+    #[test]
+    fn min_max() {
+        let low = Pressure::from(1.0);
+        let high = Pressure::from(2.0);
+        assert_eq!(low.min(high), low);
+        assert_eq!(low.max(high), high);
+
+        // NaN is ignored, matching f64::min and f64::max.
+        let nan = Pressure::from(f64::NAN);
+        assert_eq!(nan.min(low), low);
+        assert_eq!(high.max(nan), high);
+    }
+
+    #[test]
+    fn clamp() {
+        let lo = Length::from(1.0);
+        let hi = Length::from(5.0);
+        assert_eq!(Length::from(0.0).clamp(lo, hi), lo);
+        assert_eq!(Length::from(3.0).clamp(lo, hi), Length::from(3.0));
+        assert_eq!(Length::from(9.0).clamp(lo, hi), hi);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_inverted() {
+        Length::from(3.0).clamp(Length::from(5.0), Length::from(1.0));
+    }
+
+    #[test]
+    fn sum() {
+        let forces = [Force::from(1.0), Force::from(2.0), Force::from(3.0)];
+        assert_eq!(forces.iter().copied().sum::<Force>(), Force::from(6.0));
+        assert_eq!(forces.iter().sum::<Force>(), Force::from(6.0));
+        assert_eq!(
+            Vec::<Force>::new().into_iter().sum::<Force>(),
+            Force::from(0.0)
+        );
+    }
+
+    #[test]
+    fn rem() {
+        let period = Time::from(3.0);
+        assert_eq!(Time::from(7.0) % period, Time::from(1.0));
+
+        let mut time = Time::from(7.0);
+        time %= period;
+        assert_eq!(time, Time::from(1.0));
+    }
+
+    #[test]
+    fn single_precision() {
+        let length = Length::<f32>::from(2.0);
+        let time = Time::<f32>::from(4.0);
+        assert_eq!(length + length, Length::from(4.0_f32));
+        assert_eq!(length - length, Length::from(0.0_f32));
+        assert_eq!(length * length, Area::from(4.0_f32));
+        assert_eq!(length / time, Velocity::from(0.5_f32));
+        assert_eq!(2.0_f32 * length, Length::from(4.0_f32));
+        assert_eq!(1.0_f32 / time, Frequency::from(0.25_f32));
+        assert_eq!((length * length).sqrt(), length);
+        assert_eq!(
+            std::mem::size_of::<f32>(),
+            std::mem::size_of::<Length<f32>>()
+        );
+    }
+
+    #[test]
+    fn angular_velocity() {
+        let angle = Angle::from(6.0);
+        let time = Time::from(2.0);
+        assert_eq!(angle / time, AngularVelocity::from(3.0));
+    }
+
+    #[test]
+    fn torque() {
+        let force = Force::from(2.0);
+        let length = Length::from(3.0);
+        let angle = Angle::from(1.0);
+        let torque = force * length / angle;
+        assert_eq!(torque, Torque::from(6.0));
+        assert_eq!(torque * angle, Energy::from(6.0));
+
+        // Force times length alone is energy, which is a distinct type.
+        let energy: Energy = force * length;
+        assert_eq!(energy.value(), torque.value());
+        assert_ne!(
+            std::any::TypeId::of::<Energy>(),
+            std::any::TypeId::of::<Torque>()
+        );
+    }
+
+    #[test]
+    fn trigonometry() {
+        use std::f64::consts::FRAC_PI_2;
+
+        assert_eq!(Angle::from(0.0).sin(), Dimensionless::from(0.0));
+        assert_eq!(Angle::from(0.0).cos(), Dimensionless::from(1.0));
+        assert_eq!(Angle::from(0.0).tan(), Dimensionless::from(0.0));
+        assert_eq!(Dimensionless::from(1.0).asin(), Angle::from(FRAC_PI_2));
+        assert_eq!(Dimensionless::from(1.0).acos(), Angle::from(0.0));
+        assert_eq!(Dimensionless::from(0.0).atan(), Angle::from(0.0));
+    }
+
+    #[test]
+    fn atan2() {
+        use std::f64::consts::{FRAC_PI_4, PI};
+
+        let one = Length::from(1.0);
+        assert_eq!(super::atan2(one, one), Angle::from(FRAC_PI_4));
+        assert_eq!(super::atan2(one, -one), Angle::from(3.0 * FRAC_PI_4));
+        assert_eq!(super::atan2(-one, -one), Angle::from(-3.0 * FRAC_PI_4));
+        assert_eq!(super::atan2(-one, one), Angle::from(-FRAC_PI_4));
+        assert_eq!(super::atan2(Length::from(0.0), -one), Angle::from(PI));
+    }
+
+    #[test]
+    fn transcendental() {
+        assert_eq!(Dimensionless::from(0.0).exp(), Dimensionless::from(1.0));
+        assert_eq!(Dimensionless::from(1.0).ln(), Dimensionless::from(0.0));
+        assert_eq!(Dimensionless::from(100.0).log10(), Dimensionless::from(2.0));
+
+        let base = Dimensionless::from(4.0);
+        assert_eq!(
+            base.powf(Dimensionless::from(1.5)),
+            Dimensionless::from(8.0)
+        );
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(Velocity::default(), Velocity::from(0.0));
+
+        #[derive(Default)]
+        struct Body {
+            position: Length,
+            velocity: Velocity,
+            mass: Mass,
         }
+
+        let body = Body::default();
+        assert_eq!(body.position, Length::from(0.0));
+        assert_eq!(body.velocity, Velocity::from(0.0));
+        assert_eq!(body.mass, Mass::from(0.0));
     }
 
-    pub const fn sub(self, rhs: Self) -> Self {
-        Self {
-            length: self.length - rhs.length,
-            mass: self.mass - rhs.mass,
-            time: self.time - rhs.time,
+    #[test]
+    fn approximate() {
+        let a = Energy::from(0.1) + Energy::from(0.2);
+        let b = Energy::from(0.3);
+        assert_ne!(a, b);
+        assert!(a.abs_diff_eq(b, Energy::from(1e-12)));
+        assert!(!a.abs_diff_eq(Energy::from(0.4), Energy::from(1e-12)));
+
+        let epsilon = Energy::from(f64::EPSILON);
+        let max_relative = Dimensionless::from(1e-9);
+        assert!(a.relative_eq(b, epsilon, max_relative));
+        assert!(Energy::from(1e9).relative_eq(Energy::from(1e9 + 0.5), epsilon, max_relative));
+        assert!(!Energy::from(1.0).relative_eq(Energy::from(1.1), epsilon, max_relative));
+        assert!(!Energy::from(f64::INFINITY).relative_eq(Energy::from(1.0), epsilon, max_relative));
+    }
+
+    #[test]
+    fn rounding() {
+        let length = Length::from(2.7);
+        let round: Length = length.round();
+        let floor: Length = length.floor();
+        let ceil: Length = length.ceil();
+        let trunc: Length = length.trunc();
+        assert_eq!(round, Length::from(3.0));
+        assert_eq!(floor, Length::from(2.0));
+        assert_eq!(ceil, Length::from(3.0));
+        assert_eq!(trunc, Length::from(2.0));
+        assert_eq!((-length).trunc(), Length::from(-2.0));
+    }
+
+    #[test]
+    fn finiteness() {
+        let nan = Velocity::from(f64::NAN);
+        assert!(nan.is_nan());
+        assert!(!nan.is_finite());
+        assert!(!nan.is_infinite());
+
+        let infinity = Velocity::from(f64::INFINITY);
+        assert!(!infinity.is_nan());
+        assert!(!infinity.is_finite());
+        assert!(infinity.is_infinite());
+
+        let finite = Velocity::from(1.0);
+        assert!(!finite.is_nan());
+        assert!(finite.is_finite());
+        assert!(!finite.is_infinite());
+    }
+
+    #[test]
+    fn recip() {
+        assert_eq!(Time::from(2.0).recip(), Frequency::from(0.5));
+        assert_eq!(Length::from(4.0).recip().recip(), Length::from(4.0));
+    }
+
+    #[test]
+    fn mul_add() {
+        let velocity = Velocity::from(2.0);
+        let time = Time::from(3.0);
+        let position = Length::from(1.0);
+        let next: Length = velocity.mul_add(time, position);
+        assert_eq!(next, Length::from(7.0));
+    }
+
+    #[test]
+    fn total_cmp() {
+        use std::cmp::Ordering;
+
+        let one = Length::from(1.0);
+        let two = Length::from(2.0);
+        assert_eq!(one.total_cmp(&two), Ordering::Less);
+        assert_eq!(two.total_cmp(&one), Ordering::Greater);
+        assert_eq!(one.total_cmp(&one), Ordering::Equal);
+
+        let mut lengths = [
+            Length::from(f64::NAN),
+            Length::from(3.0),
+            Length::from(-1.0),
+            Length::from(2.0),
+        ];
+        Length::sort_quantities(&mut lengths);
+        assert_eq!(&lengths[..3], &[Length::from(-1.0), two, Length::from(3.0)]);
+        assert!(lengths[3].is_nan());
+
+        let mut sorted = lengths;
+        sorted.sort_by(Quantity::total_cmp);
+        assert_eq!(
+            sorted.map(|l| l.value().to_bits()),
+            lengths.map(|l| l.value().to_bits())
+        );
+    }
+
+    #[test]
+    fn unit_arithmetic() {
+        let [length, mass, time, ..] = Force::<f64>::UNIT.exponents();
+        assert_eq!(length, Ratio::int(1));
+        assert_eq!(mass, Ratio::int(1));
+        assert_eq!(time, Ratio::int(-2));
+
+        let velocity = Length::<f64>::UNIT.sub(Time::<f64>::UNIT);
+        assert_eq!(velocity, Velocity::<f64>::UNIT);
+        let momentum = Mass::<f64>::UNIT.add(velocity);
+        assert_eq!(momentum.sub(Time::<f64>::UNIT), Force::<f64>::UNIT);
+        assert_eq!(Time::<f64>::UNIT.neg(), Frequency::<f64>::UNIT);
+        assert_eq!(Length::<f64>::UNIT.mul(3), Volume::<f64>::UNIT);
+        assert_eq!(Area::<f64>::UNIT.half(), Length::<f64>::UNIT);
+    }
+
+    #[test]
+    fn custom_dimensions() {
+        let length = Units::new([Ratio::int(1), Ratio::int(0), Ratio::int(0)]);
+        let time = Units::new([Ratio::int(0), Ratio::int(0), Ratio::int(1)]);
+        let velocity = length.sub(time);
+        assert_eq!(
+            velocity.exponents(),
+            [Ratio::int(1), Ratio::int(0), Ratio::int(-1)]
+        );
+        assert_eq!(velocity.neg().neg(), velocity);
+    }
+
+    #[test]
+    fn momentum() {
+        assert_eq!(Mass::from(2.0) * Velocity::from(3.0), Momentum::from(6.0));
+        assert_eq!(Force::from(4.0) * Time::from(0.5), Impulse::from(2.0));
+        assert_eq!(Energy::from(3.0) * Time::from(2.0), Action::from(6.0));
+
+        let momentum: Momentum = Impulse::from(1.0);
+        assert_eq!(momentum, Momentum::from(1.0));
+    }
+
+    #[test]
+    fn density() {
+        let density = Mass::from(10.0) / Volume::from(2.0);
+        assert_eq!(density, Density::from(5.0));
+        assert_eq!(1.0 / density, SpecificVolume::from(0.2));
+        assert_eq!(
+            Volume::from(6.0) / Time::from(2.0),
+            VolumetricFlowRate::from(3.0)
+        );
+        assert_eq!(
+            density * VolumetricFlowRate::from(3.0),
+            MassFlowRate::from(15.0)
+        );
+    }
+
+    #[test]
+    fn lerp() {
+        let a = Length::from(2.0);
+        let b = Length::from(6.0);
+        assert_eq!(a.lerp(b, Dimensionless::from(0.0)), a);
+        assert_eq!(a.lerp(b, Dimensionless::from(0.5)), Length::from(4.0));
+        assert_eq!(a.lerp(b, Dimensionless::from(1.0)), b);
+        assert_eq!(a.lerp(b, Dimensionless::from(1.5)), Length::from(8.0));
+    }
+
+    #[test]
+    fn hypot() {
+        assert_eq!(
+            Length::from(3.0).hypot(Length::from(4.0)),
+            Length::from(5.0)
+        );
+        let large = Length::from(3e200).hypot(Length::from(4e200));
+        assert!(large.relative_eq(
+            Length::from(5e200),
+            Length::from(0.0),
+            Dimensionless::from(1e-15)
+        ));
+    }
+
+    #[test]
+    fn copysign() {
+        let speed = Velocity::from(5.0);
+        assert_eq!(speed.copysign(Velocity::from(-1.0)), Velocity::from(-5.0));
+        assert_eq!((-speed).copysign(Velocity::from(2.0)), speed);
+    }
+
+    #[test]
+    fn const_ops() {
+        const BASE: Velocity = Quantity::from_value(10.0);
+        const VELOCITIES: [Velocity; 3] = [
+            BASE.const_add(Quantity::from_value(5.0)),
+            BASE.const_sub(Quantity::from_value(2.5)).const_mul(2.0),
+            BASE.const_neg().const_div(4.0),
+        ];
+        assert_eq!(
+            VELOCITIES,
+            [
+                Velocity::from(15.0),
+                Velocity::from(15.0),
+                Velocity::from(-2.5)
+            ]
+        );
+    }
+
+    #[test]
+    fn fract() {
+        let length = Length::from(3.25);
+        assert_eq!(length.fract(), Length::from(0.25));
+        assert_eq!(Length::from(-3.25).fract(), Length::from(-0.25));
+
+        let (int, fract) = length.split_int_fract();
+        assert_eq!(int, Length::from(3.0));
+        assert_eq!(int + fract, length);
+    }
+
+    #[test]
+    fn base_f64() {
+        assert_eq!(Length::from_base_f64(2.5), Length::from(2.5));
+        assert_eq!(Length::from(2.5).to_base_f64(), 2.5);
+        assert_eq!(Length::from_milli(5.0).to_base_f64(), 0.005);
+        assert_eq!(Time::<f32>::from_base_f64(0.5), Time::from(0.5_f32));
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", Force::from(1.5)),
+            "Quantity(1.5 m^1 kg^1 s^-2 A^0 K^0 rad^0)"
+        );
+        assert_eq!(
+            format!("{:?}", Length::from(4.0_f32).sqrt()),
+            "Quantity(2.0 m^(1/2) kg^0 s^0 A^0 K^0 rad^0)"
+        );
+    }
+
+    #[test]
+    fn write_units() {
+        struct Buffer {
+            bytes: [u8; 64],
+            len: usize,
+        }
+
+        impl core::fmt::Write for Buffer {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                let end = self.len + s.len();
+                let bytes = self.bytes.get_mut(self.len..end).ok_or(core::fmt::Error)?;
+                bytes.copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
         }
+
+        let mut buffer = Buffer {
+            bytes: [0; 64],
+            len: 0,
+        };
+        Force::from(2.0).write_units(&mut buffer).unwrap();
+        let written = core::str::from_utf8(&buffer.bytes[..buffer.len]).unwrap();
+        assert_eq!(written, "2.0 m^1 kg^1 s^-2 A^0 K^0 rad^0");
+        assert_eq!(written, Force::from(2.0).format_units());
+
+        let mut string = String::new();
+        Force::from(2.0).write_units(&mut string).unwrap();
+        assert_eq!(written, string);
+
+        buffer.len = 0;
+        write!(buffer, "{:.2}", Velocity::from(1.5)).unwrap();
+        assert_eq!(&buffer.bytes[..buffer.len], b"1.50 m s^-1");
     }
 
-    pub const fn neg(self) -> Self {
-        Self {
-            length: -self.length,
-            mass: -self.mass,
-            time: -self.time,
+    #[test]
+    fn dimensionless_factor() {
+        let half = Dimensionless::from(0.5);
+        let force: Force = Force::from(6.0) * half;
+        assert_eq!(force, Force::from(3.0));
+        assert_eq!(Force::from(6.0) / half, Force::from(12.0));
+
+        fn type_id<T: 'static>(_: T) -> std::any::TypeId {
+            std::any::TypeId::of::<T>()
         }
+        assert_eq!(
+            type_id(Force::from(6.0) * half),
+            std::any::TypeId::of::<Force>()
+        );
+        assert_eq!(
+            type_id(Force::from(6.0) / half),
+            std::any::TypeId::of::<Force>()
+        );
     }
-}
 
-#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
-pub struct Quantity<const UNIT: Unit>(f64);
+    #[test]
+    fn viscosity() {
+        let dynamic = Pressure::from(2.0) * Time::from(3.0);
+        assert_eq!(dynamic, DynamicViscosity::from(6.0));
+        assert_eq!(dynamic / Density::from(2.0), KinematicViscosity::from(3.0));
 
-macro_rules! quantity {
-    ($name: ident, $length:literal, $mass: literal, $time: literal) => {
-        pub type $name = Quantity<
-            {
-                Unit {
-                    length: $length,
-                    mass: $mass,
-                    time: $time,
-                }
-            },
-        >;
-    };
-}
+        let diffusivity: Diffusivity = Area::from(4.0) / Time::from(2.0);
+        assert_eq!(diffusivity, KinematicViscosity::from(2.0));
+    }
 
-// Base units
-quantity!(Dimensionless, 0, 0, 0);
-quantity!(Length, 1, 0, 0);
-quantity!(Mass, 0, 1, 0);
-quantity!(Time, 0, 0, 1);
+    #[test]
+    fn jerk() {
+        assert_eq!(Acceleration::from(6.0) / Time::from(2.0), Jerk::from(3.0));
+        assert_eq!(Jerk::from(8.0) / Time::from(2.0), Snap::from(4.0));
+        assert_eq!(
+            Snap::from(1.0) * Time::from(2.0).powi::<4>(),
+            Length::from(16.0)
+        );
+    }
 
-// Derived units
-quantity!(Area, 2, 0, 0);
-quantity!(Volume, 3, 0, 0);
-quantity!(Velocity, 1, 0, -1);
-quantity!(Acceleration, 1, 0, -2);
-quantity!(Force, 1, 1, -2);
-quantity!(Frequency, 0, 0, -1);
-quantity!(Pressure, -1, 1, -2);
-quantity!(Energy, 2, 1, -2);
-quantity!(Power, 2, 1, -3);
-
-impl<const UNIT: Unit> Quantity<UNIT> {
-    pub fn format_units(self) -> String {
-        let value = self.0;
-        let length = UNIT.length;
-        let mass = UNIT.mass;
-        let time = UNIT.time;
-        format!("{value:0.1} m^{length} kg^{mass} s^{time}")
+    #[test]
+    fn checked() {
+        let max = Length::from(f64::MAX);
+        assert_eq!(max.checked_add(max), None);
+        assert_eq!((-max).checked_sub(max), None);
+        assert_eq!(
+            Length::from(1.0).checked_add(Length::from(2.0)),
+            Some(Length::from(3.0))
+        );
+        assert_eq!(
+            Length::from(1.0).checked_sub(Length::from(2.0)),
+            Some(Length::from(-1.0))
+        );
+        let infinity = Length::from(f64::INFINITY);
+        assert_eq!(infinity.checked_add(Length::from(1.0)), Some(infinity));
     }
-}
 
-impl<const UNIT: Unit> From<f64> for Quantity<UNIT> {
-    fn from(value: f64) -> Self {
-        Self(value)
+    #[test]
+    fn saturating() {
+        assert_eq!(Pressure::from(-5.0).non_negative(), Pressure::from(0.0));
+        assert_eq!(Pressure::from(5.0).non_negative(), Pressure::from(5.0));
+
+        let lo = Dimensionless::from(0.0);
+        let hi = Dimensionless::from(1.0);
+        let throttle = Dimensionless::from(0.3);
+        assert_eq!(throttle.saturating_to(lo, hi), throttle);
+        assert_eq!(Dimensionless::from(1.5).saturating_to(lo, hi), hi);
     }
-}
 
-impl<const UNIT: Unit> Add for Quantity<UNIT> {
-    type Output = Self;
+    #[test]
+    fn format_units_compact() {
+        assert_eq!(Dimensionless::from(1.0).format_units_compact(), "1.0");
+        assert_eq!(Length::from(1.0).format_units_compact(), "1.0 m");
+        assert_eq!(Velocity::from(2.5).format_units_compact(), "2.5 m s^-1");
+        assert_eq!(Force::from(1.0).format_units_compact(), "1.0 m kg s^-2");
+        assert_eq!(
+            Length::from(4.0).sqrt().format_units_compact(),
+            "2.0 m^(1/2)"
+        );
+    }
 
-    fn add(self, rhs: Self) -> Self::Output {
-        Self(self.0 + rhs.0)
+    #[test]
+    fn cast_unit() {
+        let work = Force::from(2.0) * Length::from(3.0);
+        let energy: Energy = work.cast_unit();
+        assert_eq!(energy, Energy::from(6.0));
+        assert_eq!(
+            Length::from(1.0).cast_unit::<{ Length::<f64>::UNIT }>(),
+            Length::from(1.0)
+        );
+        assert!(Force::<f64>::UNIT.equals(Force::<f64>::UNIT));
+        assert!(!Energy::<f64>::UNIT.equals(Torque::<f64>::UNIT));
     }
-}
 
-impl<const UNIT: Unit> Sub for Quantity<UNIT> {
-    type Output = Self;
+    #[test]
+    fn integrate() {
+        let dt = Time::from(0.5);
+        let position = Length::from(1.0) + Velocity::from(4.0).integrate(dt);
+        assert_eq!(position, Length::from(3.0));
+        let jerk: Jerk = Acceleration::from(3.0).differentiate(dt);
+        assert_eq!(jerk, Jerk::from(6.0));
+    }
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self(self.0 - rhs.0)
+    #[test]
+    fn from_iter() {
+        let energies = vec![Energy::from(1.0), Energy::from(2.5), Energy::from(0.5)];
+        let total: Energy = energies.into_iter().collect();
+        assert_eq!(total, Energy::from(4.0));
+
+        let empty: Energy = std::iter::empty().collect();
+        assert_eq!(empty, Energy::from(0.0));
     }
-}
 
-impl<const UNIT: Unit> AddAssign for Quantity<UNIT> {
-    fn add_assign(&mut self, rhs: Self) {
-        self.0 += rhs.0;
+    #[test]
+    fn wavenumber() {
+        assert_eq!(1.0 / Length::from(2.0), Wavenumber::from(0.5));
+        let curvature: Curvature = 1.0 / Length::from(4.0);
+        assert_eq!(curvature, Wavenumber::from(0.25));
+        assert_eq!(
+            Energy::from(10.0) / Mass::from(2.0),
+            SpecificEnergy::from(5.0)
+        );
+        assert_eq!(
+            Velocity::from(3.0) * Velocity::from(3.0),
+            SpecificEnergy::from(9.0)
+        );
     }
-}
 
-impl<const UNIT: Unit> SubAssign for Quantity<UNIT> {
-    fn sub_assign(&mut self, rhs: Self) {
-        self.0 -= rhs.0;
+    #[test]
+    fn map() {
+        let clamp = |x: f64| x.clamp(0.0, 10.0);
+        assert_eq!(Length::from(12.0).map(clamp), Length::from(10.0));
+        assert_eq!(Length::from(-1.0).map(clamp), Length::from(0.0));
+        assert_eq!(Length::from(3.0).map(clamp), Length::from(3.0));
     }
-}
 
-impl<const UNIT: Unit> Neg for Quantity<UNIT> {
-    type Output = Self;
+    #[test]
+    fn zip_map() {
+        let a = Length::from(2.0);
+        let b = Length::from(5.0);
+        assert_eq!(a.zip_map(b, |x, y| (x + y) / 2.0), Length::from(3.5));
+        assert_eq!(a.zip_map(b, f64::max), b);
+    }
 
-    fn neg(self) -> Self::Output {
-        Self(-self.0)
+    #[test]
+    fn spring_constant() {
+        assert_eq!(
+            Force::from(10.0) / Length::from(2.0),
+            SpringConstant::from(5.0)
+        );
+        let stiffness: Stiffness = SurfaceTension::from(1.0);
+        assert_eq!(stiffness * Length::from(3.0), Force::from(3.0));
     }
-}
 
-impl<const LHS_UNIT: Unit, const RHS_UNIT: Unit> Mul<Quantity<RHS_UNIT>> for Quantity<LHS_UNIT>
-where
-    Quantity<{ LHS_UNIT.add(RHS_UNIT) }>: Sized,
-{
-    type Output = Quantity<{ LHS_UNIT.add(RHS_UNIT) }>;
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn references() {
+        let a = Length::from(2.0);
+        let b = Length::from(3.0);
+        let area: Area = &a * &b;
+        assert_eq!(area, Area::from(6.0));
+        assert_eq!(a * &b, area);
+        assert_eq!(&a * b, area);
+        assert_eq!(&area / &a, b);
+        assert_eq!(&a + &b, Length::from(5.0));
+        assert_eq!(&b - a, Length::from(1.0));
 
-    fn mul(self, rhs: Quantity<RHS_UNIT>) -> Self::Output {
-        Quantity(self.0 * rhs.0)
+        let lengths = [a, b];
+        let areas: Vec<Area> = lengths.iter().map(|l| l * l).collect();
+        assert_eq!(areas, [Area::from(4.0), Area::from(9.0)]);
     }
-}
 
-impl<const LHS_UNIT: Unit, const RHS_UNIT: Unit> Div<Quantity<RHS_UNIT>> for Quantity<LHS_UNIT>
-where
-    Quantity<{ LHS_UNIT.sub(RHS_UNIT) }>: Sized,
-{
-    type Output = Quantity<{ LHS_UNIT.sub(RHS_UNIT) }>;
+    #[test]
+    fn format_units_precision() {
+        let length = Length::from(1.23456);
+        let units = "m^1 kg^0 s^0 A^0 K^0 rad^0";
+        assert_eq!(length.format_units_precision(0), format!("1 {units}"));
+        assert_eq!(length.format_units_precision(2), format!("1.23 {units}"));
+        assert_eq!(length.format_units_precision(4), format!("1.2346 {units}"));
+        assert_eq!(length.format_units(), length.format_units_precision(1));
+    }
 
-    fn div(self, rhs: Quantity<RHS_UNIT>) -> Self::Output {
-        Quantity(self.0 / rhs.0)
+    #[test]
+    fn magnitude_direction() {
+        let force = Force::from(-4.0);
+        let (magnitude, direction) = force.magnitude_direction();
+        assert_eq!(magnitude, Force::from(4.0));
+        assert_eq!(direction, Dimensionless::from(-1.0));
+        assert_eq!(magnitude * direction.value(), force);
     }
-}
 
-impl<const UNIT: Unit> Div<Quantity<UNIT>> for f64
-where
-    Quantity<{ UNIT.neg() }>: Sized,
-{
-    type Output = Quantity<{ UNIT.neg() }>;
+    #[test]
+    fn distinct_units() {
+        macro_rules! table {
+            ($($name: ident),* $(,)?) => {
+                [$((stringify!($name), $name::<f64>::UNIT)),*]
+            };
+        }
+
+        let units = table!(
+            Dimensionless,
+            Length,
+            Mass,
+            Time,
+            Current,
+            Temperature,
+            Angle,
+            Area,
+            Wavenumber,
+            Volume,
+            Velocity,
+            Acceleration,
+            Jerk,
+            Snap,
+            Force,
+            Frequency,
+            Pressure,
+            SurfaceTension,
+            Energy,
+            SpecificEnergy,
+            Power,
+            Charge,
+            Voltage,
+            Resistance,
+            Capacitance,
+            HeatCapacity,
+            ThermalConductivity,
+            EntropyRate,
+            AngularVelocity,
+            Torque,
+            Momentum,
+            Action,
+            Density,
+            SpecificVolume,
+            MassFlowRate,
+            VolumetricFlowRate,
+            DynamicViscosity,
+            KinematicViscosity,
+            ThermalExpansionCoefficient,
+            TemperatureGradient,
+        );
 
-    fn div(self, rhs: Quantity<UNIT>) -> Self::Output {
-        Quantity(self / rhs.0)
+        // Pairs of `quantity!` types that are meant to share a unit. Quantities
+        // that are the same physically, like impulse and momentum, are type
+        // aliases instead, and torque is told apart from energy by its angle.
+        const SHARED: [(&str, &str); 0] = [];
+
+        for (i, (a, unit_a)) in units.iter().enumerate() {
+            for (b, unit_b) in &units[i + 1..] {
+                assert!(
+                    unit_a != unit_b || SHARED.contains(&(a, b)),
+                    "{a} and {b} have the same unit `{unit_a}`"
+                );
+            }
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn into_scalar() {
+        let x: f64 = Length::from(2.0).into();
+        assert_eq!(x, 2.0);
+        let y: f32 = Time::from(0.5_f32).into();
+        assert_eq!(y, 0.5);
+
+        let forces = [Force::from(1.0), Force::from(2.0)];
+        let values: Vec<&f64> = forces.iter().map(AsRef::as_ref).collect();
+        assert_eq!(values, [&1.0, &2.0]);
+    }
 
     #[test]
-    fn size_of() {
-        assert_eq!(std::mem::size_of::<f64>(), std::mem::size_of::<Length>());
+    fn powr() {
+        assert_eq!(Area::from(9.0).powr::<1, 2>(), Length::from(3.0));
+        assert_eq!(Length::from(2.0).powr::<3, 1>(), Volume::from(8.0));
+        let root = Length::from(4.0).powr::<1, 2>();
+        assert_eq!(root, Length::from(4.0).sqrt());
+        assert_eq!(root.powr::<4, 1>(), Area::from(16.0));
+        assert_eq!(
+            Dimensionless::from(4.0).powf(Dimensionless::from(2.5)),
+            Dimensionless::from(32.0)
+        );
     }
 
     #[test]
-    fn arithmetic() {
-        let dimensionless = Dimensionless::from(1.0);
-        assert_eq!(dimensionless + dimensionless, Dimensionless::from(2.0));
-        assert_eq!(dimensionless - dimensionless, Dimensionless::from(0.0));
+    fn negate() {
+        let mut velocity = Velocity::from(3.0);
+        velocity.negate();
+        let flipped: Velocity = velocity;
+        assert_eq!(flipped, Velocity::from(-3.0));
+        assert_eq!(-&flipped, Velocity::from(3.0));
+    }
 
-        let mut dimensionless = Dimensionless::from(0.0);
-        dimensionless += Dimensionless::from(1.0);
-        assert_eq!(dimensionless, Dimensionless::from(1.0));
+    #[test]
+    fn assert_quantity_eq() {
+        assert_quantity_eq!(
+            Length::from(1.0),
+            Length::from(1.0 + 1e-12),
+            Length::from(1e-9)
+        );
+        assert_quantity_eq!(0.1.meters() + 0.2.meters(), 0.3.meters(), 1e-12.meters());
+    }
 
-        let mut dimensionless = Dimensionless::from(0.0);
-        dimensionless -= Dimensionless::from(1.0);
-        assert_eq!(dimensionless, Dimensionless::from(-1.0));
+    #[test]
+    #[should_panic(expected = "left: 1.0 m^1 kg^0 s^0 A^0 K^0 rad^0\n  right: 1.5 m^1")]
+    fn assert_quantity_eq_fails() {
+        assert_quantity_eq!(Length::from(1.0), Length::from(1.5), Length::from(0.1));
+    }
 
-        let dimensionless = Dimensionless::from(1.0);
-        assert_eq!(-dimensionless, Dimensionless::from(-1.0));
+    #[test]
+    fn frequency_period() {
+        assert_eq!(Time::from(0.5).frequency(), Frequency::from(2.0));
+        assert_eq!(Time::from(0.5).frequency().period(), Time::from(0.5));
+        assert_eq!(Frequency::from(4.0).period(), Time::from(0.25));
+        assert_eq!(Time::from(0.5f32).frequency(), Frequency::from(2.0f32));
     }
 
     #[test]
-    fn formatting() {
-        let length = Length::from(1.0);
-        let format = length.format_units();
-        assert_eq!("1.0 m^1 kg^0 s^0", format);
+    fn unit_accessors() {
+        let unit = Force::from(1.0).unit();
+        assert_eq!(unit, Force::<f64>::UNIT);
+        assert_eq!(unit.length(), Ratio::int(1));
+        assert_eq!(unit.mass(), Ratio::int(1));
+        assert_eq!(unit.time(), Ratio::int(-2));
+        assert_eq!(unit.current(), Ratio::int(0));
+        assert_eq!(unit.temperature(), Ratio::int(0));
+        assert_eq!(unit.angle(), Ratio::int(0));
+        assert_eq!(Length::from(4.0).sqrt().unit().length(), Ratio::new(1, 2));
     }
 
     #[test]
-    fn length() {
-        let length = Length::from(1.0);
-        let dimensionless = length / length;
-        assert_eq!(dimensionless, Dimensionless::from(1.0));
+    fn from_integers() {
+        assert_eq!(Unit::from_integers([1, 1, -2, 0, 0, 0]), Force::<f64>::UNIT);
+        assert!(Unit::from_integers([0; 6]).is_dimensionless());
+        assert!(Dimensionless::<f64>::UNIT.is_dimensionless());
+        assert!(!Length::<f64>::UNIT.is_dimensionless());
+        assert!(Length::<f64>::UNIT
+            .sub(Length::<f64>::UNIT)
+            .is_dimensionless());
     }
 
     #[test]
-    fn area() {
-        let length = Length::from(1.0);
-        let area = length * length;
-        assert_eq!(area, Area::from(1.0));
+    fn percent() {
+        let efficiency = Dimensionless::from_percent(50.0);
+        assert_eq!(efficiency, Dimensionless::from(0.5));
+        assert_eq!(efficiency.to_percent(), 50.0);
+        assert_eq!(Dimensionless::from(0.25).to_percent(), 25.0);
+        assert_eq!(Dimensionless::<f32>::from_percent(10.0).to_percent(), 10.0);
+        assert_eq!(efficiency.format_percent(), "50.0%");
+        assert_eq!(Dimensionless::from(1.234).format_percent(), "123.4%");
     }
 
     #[test]
-    fn volume() {
-        let length = Length::from(1.0);
-        let volume = length * length * length;
-        assert_eq!(volume, Volume::from(1.0));
+    fn to_integers() {
+        let exponents = Force::<f64>::UNIT.to_integers().unwrap();
+        assert_eq!(exponents, [1, 1, -2, 0, 0, 0]);
+        assert_eq!(Unit::from_integers(exponents), Force::<f64>::UNIT);
+        assert_eq!(Length::<f64>::UNIT.half().to_integers(), None);
+        assert_eq!(Ratio::new(4, 2).to_integer(), Some(2));
+        assert_eq!(Ratio::new(1, 2).to_integer(), None);
     }
 
     #[test]
-    fn velocity() {
-        let length = Length::from(1.0);
-        let time = Time::from(1.0);
-        let velocity = length / time;
-        assert_eq!(velocity, Velocity::from(1.0));
+    fn signed_mul() {
+        let (ax, ay) = (Length::from(1.0), Length::from(0.0));
+        let (bx, by) = (Length::from(0.0), Length::from(2.0));
+        let counterclockwise: Area = ax.signed_mul(by) - ay.signed_mul(bx);
+        let clockwise: Area = bx.signed_mul(ay) - by.signed_mul(ax);
+        assert_eq!(counterclockwise, Area::from(2.0));
+        assert_eq!(clockwise, Area::from(-2.0));
+        assert_eq!(
+            Length::from(-3.0).signed_mul(Length::from(2.0)),
+            Area::from(-6.0)
+        );
     }
 
     #[test]
-    fn acceleration() {
-        let length = Length::from(1.0);
-        let time = Time::from(1.0);
-        let acceleration = length / (time * time);
-        assert_eq!(acceleration, Acceleration::from(1.0));
+    fn scale_by() {
+        fn force_only(force: Force) -> f64 {
+            force.value()
+        }
+        let force = Force::from(3.0).scale_by(Dimensionless::from(0.5));
+        assert_eq!(force_only(force), 1.5);
+
+        fn halve<const UNIT: Unit>(quantity: Quantity<UNIT>) -> Quantity<UNIT> {
+            quantity.scale_by(Dimensionless::from(0.5))
+        }
+        assert_eq!(force_only(halve(Force::from(3.0))), 1.5);
     }
 
     #[test]
-    fn force() {
-        let length = Length::from(1.0);
-        let mass = Mass::from(1.0);
-        let time = Time::from(1.0);
-        let acceleration = length / (time * time);
-        let force = mass * acceleration;
-        assert_eq!(force, Force::from(1.0));
+    fn thermal_expansion() {
+        let coefficient: ThermalExpansionCoefficient = Temperature::from(100.0).recip();
+        assert_eq!(coefficient, 0.01.per_kelvin());
+
+        // Steel expands by about 12e-6 per kelvin.
+        let length = Length::from(2.0);
+        let delta: TemperatureDifference = Temperature::from(320.0) - Temperature::from(300.0);
+        let expansion: Length = length * 12e-6.per_kelvin() * delta;
+        assert!(expansion.relative_eq(Length::from(4.8e-4), Length::from(0.0), Quantity(1e-12)));
+
+        let gradient: TemperatureGradient = delta / Length::from(0.5);
+        assert_eq!(gradient, 40.0.kelvins_per_meter());
     }
 
     #[test]
-    fn frequency() {
-        let time = Time::from(1.0);
-        let frequency = 1.0 / time;
-        assert_eq!(frequency, Frequency::from(1.0));
+    fn zero() {
+        assert_eq!(Force::ZERO, Force::from(0.0));
+        assert_eq!(Length::<f32>::ZERO, Length::from(0.0f32));
+
+        let forces = [Force::from(1.0), Force::from(2.5)];
+        let mut total = Force::ZERO;
+        for force in forces {
+            total += force;
+        }
+        assert_eq!(total, forces.iter().sum::<Force>());
+        assert_eq!(Force::ZERO + total, total);
+        assert_eq!(core::iter::empty::<Force>().sum::<Force>(), Force::ZERO);
     }
 
     #[test]
-    fn pressure() {
-        let length = Length::from(1.0);
-        let mass = Mass::from(1.0);
-        let time = Time::from(1.0);
-        let pressure = (1.0 / length) * mass / (time * time);
-        assert_eq!(pressure, Pressure::from(1.0));
+    fn midpoint() {
+        assert_eq!(
+            Length::from(2.0).midpoint(Length::from(4.0)),
+            Length::from(3.0)
+        );
+        assert_eq!(Length::from(-1.0).midpoint(Length::from(1.0)), Length::ZERO);
+
+        let max = Length::from(f64::MAX);
+        assert!(((max + max) / 2.0).value().is_infinite());
+        assert_eq!(max.midpoint(max), max);
+        assert_eq!(
+            max.midpoint(Length::from(f64::MAX / 2.0)),
+            Length::from(f64::MAX * 0.75)
+        );
     }
 
     #[test]
-    fn energy() {
-        let length = Length::from(1.0);
-        let mass = Mass::from(1.0);
-        let time = Time::from(1.0);
-        let energy = (length * length) * mass / (time * time);
-        assert_eq!(energy, Energy::from(1.0));
+    fn dimension_algebra() {
+        let length = Length::<f64>::UNIT;
+        let time = Time::<f64>::UNIT;
+        assert!(length.is_compatible_add(length));
+        assert!(!length.is_compatible_add(time));
+        assert!(!Torque::<f64>::UNIT.is_compatible_add(Energy::<f64>::UNIT));
+        assert!(Area::<f64>::UNIT.half().is_compatible_add(length));
+
+        assert_eq!(length.result_of_div(time), Velocity::<f64>::UNIT);
+        assert_eq!(length.result_of_mul(length), Area::<f64>::UNIT);
+        assert_eq!(
+            Force::<f64>::UNIT.result_of_mul(length),
+            Energy::<f64>::UNIT
+        );
+        assert_eq!(length.result_of_div(length), Dimensionless::<f64>::UNIT);
     }
 
     #[test]
-    fn power() {
-        let length = Length::from(1.0);
-        let mass = Mass::from(1.0);
-        let time = Time::from(1.0);
-        let energy = (length * length) * mass / (time * time);
-        let power = energy / time;
-        assert_eq!(power, Power::from(1.0));
+    fn clamp_magnitude() {
+        let max = Force::from(10.0);
+        assert_eq!(Force::from(25.0).clamp_magnitude(max), Force::from(10.0));
+        assert_eq!(Force::from(-25.0).clamp_magnitude(max), Force::from(-10.0));
+        assert_eq!(Force::from(-4.0).clamp_magnitude(max), Force::from(-4.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "maximum magnitude must be non-negative")]
+    #[cfg(debug_assertions)]
+    fn clamp_magnitude_negative_max() {
+        Force::from(1.0).clamp_magnitude(Force::from(-1.0));
+    }
+
+    #[test]
+    fn range_step() {
+        let steps: Vec<Time> =
+            Time::range_step(Time::ZERO, Time::from(1.0), Time::from(0.5)).collect();
+        assert_eq!(steps, [Time::from(0.0), Time::from(0.5), Time::from(1.0)]);
+
+        let down = Length::range_step(Length::from(1.0), Length::from(-0.5), Length::from(-0.5));
+        assert_eq!(down.count(), 4);
+
+        let tenths = Time::range_step(Time::ZERO, Time::from(0.95), Time::from(0.1));
+        assert_eq!(tenths.last(), Some(Time::from(0.9)));
+
+        let ramp = |start: f64, stop: f64, step: f64| {
+            Length::range_step(Length::from(start), Length::from(stop), Length::from(step)).count()
+        };
+        assert_eq!(ramp(0.0, 1.0, -0.5), 0);
+        assert_eq!(ramp(1.0, 0.0, 0.5), 0);
+        assert_eq!(ramp(0.0, 1.0, 0.0), 0);
+        assert_eq!(ramp(2.0, 2.0, 1.0), 1);
     }
 }
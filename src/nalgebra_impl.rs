@@ -0,0 +1,53 @@
+// `nalgebra::Scalar` is implemented for every `'static + Clone + PartialEq +
+// Debug` type, so quantities can already be stored in vectors and matrices.
+// This feature additionally enables `num-traits`, whose `Zero` is what
+// `Matrix::zeros` and friends need.
+//
+// Supported is everything that keeps the unit: element-wise addition and
+// subtraction, negation, and scaling by a plain number via `map`. Operations
+// whose result has a different unit, such as scaling by another quantity, dot
+// products or matrix multiplication, have to go through `map` or `zip_map`:
+// nalgebra's operators require `T * T = T`, which only `Dimensionless` meets.
+// For the same reason the `Field`/`ComplexField` based methods, e.g. `norm`,
+// are not available.
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use nalgebra::Vector3;
+
+    #[test]
+    fn element_wise() {
+        let a = Vector3::new(Length::from(1.0), Length::from(2.0), Length::from(3.0));
+        let b = Vector3::new(Length::from(4.0), Length::from(5.0), Length::from(6.0));
+        let sum = a + b;
+        assert_eq!(
+            sum,
+            Vector3::new(Length::from(5.0), Length::from(7.0), Length::from(9.0))
+        );
+        assert_eq!(sum - b, a);
+        assert_eq!(-a + a, Vector3::zeros());
+        assert_eq!(a.map(|x| x * 2.0), a + a);
+    }
+
+    #[test]
+    fn integrate() {
+        let velocity = Vector3::new(
+            Velocity::from(1.0),
+            Velocity::from(-2.0),
+            Velocity::from(0.5),
+        );
+        let time = Time::from(2.0);
+        let displacement: Vector3<Length> = velocity.map(|v| v * time);
+        assert_eq!(
+            displacement,
+            Vector3::new(Length::from(2.0), Length::from(-4.0), Length::from(1.0))
+        );
+
+        let position = Vector3::from_element(Length::from(1.0)) + displacement;
+        assert_eq!(position.x, Length::from(3.0));
+
+        let dot: Area = position.zip_map(&position, |a, b| a * b).sum();
+        assert_eq!(dot, Area::from(9.0 + 9.0 + 4.0));
+    }
+}
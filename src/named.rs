@@ -0,0 +1,80 @@
+use crate::*;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+// Derived units with a name of their own, looked up by their exponents.
+pub(crate) const NAMED_UNITS: [(&str, &str, Unit); 9] = [
+    ("Hz", "hertz", Frequency::<f64>::UNIT),
+    ("N", "newton", Force::<f64>::UNIT),
+    ("Pa", "pascal", Pressure::<f64>::UNIT),
+    ("J", "joule", Energy::<f64>::UNIT),
+    ("W", "watt", Power::<f64>::UNIT),
+    ("C", "coulomb", Charge::<f64>::UNIT),
+    ("V", "volt", Voltage::<f64>::UNIT),
+    ("Ω", "ohm", Resistance::<f64>::UNIT),
+    ("F", "farad", Capacitance::<f64>::UNIT),
+];
+
+fn lookup(unit: Unit) -> Option<(&'static str, &'static str)> {
+    NAMED_UNITS
+        .iter()
+        .find(|(_, _, named)| *named == unit)
+        .map(|&(symbol, name, _)| (symbol, name))
+}
+
+impl<const UNIT: Unit, S: Scalar> Quantity<UNIT, S> {
+    // Uses the symbol of a named derived unit such as `N` or `Pa` where one
+    // exists, and `format_units_compact` otherwise.
+    #[cfg(feature = "alloc")]
+    pub fn format_named(self) -> String {
+        match lookup(UNIT) {
+            Some((symbol, _)) => alloc::format!("{:.1} {symbol}", self.0),
+            None => self.format_units_compact(),
+        }
+    }
+
+    // Full name of a named derived unit, e.g. `newton` for a `Force`.
+    pub fn unit_name(self) -> Option<&'static str> {
+        lookup(UNIT).map(|(_, name)| name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn format_named() {
+        assert_eq!(Frequency::from(50.0).format_named(), "50.0 Hz");
+        assert_eq!(Force::from(1.0).format_named(), "1.0 N");
+        assert_eq!(Pressure::from(1.0).format_named(), "1.0 Pa");
+        assert_eq!(Energy::from(1.0).format_named(), "1.0 J");
+        assert_eq!(Power::from(1.0).format_named(), "1.0 W");
+        assert_eq!(Charge::from(1.0).format_named(), "1.0 C");
+        assert_eq!(Voltage::from(1.0).format_named(), "1.0 V");
+        assert_eq!(Resistance::from(1.0).format_named(), "1.0 Ω");
+        assert_eq!(Capacitance::from(1.0).format_named(), "1.0 F");
+    }
+
+    #[test]
+    fn fallback() {
+        assert_eq!(Velocity::from(2.0).format_named(), "2.0 m s^-1");
+        assert_eq!(Torque::from(3.0).format_named(), "3.0 m^2 kg s^-2 rad^-1");
+        assert_eq!(Dimensionless::from(0.5).format_named(), "0.5");
+    }
+
+    #[test]
+    fn unit_name() {
+        assert_eq!(Frequency::from(1.0).unit_name(), Some("hertz"));
+        assert_eq!(Force::from(1.0).unit_name(), Some("newton"));
+        assert_eq!(Pressure::from(1.0).unit_name(), Some("pascal"));
+        assert_eq!(Energy::from(1.0).unit_name(), Some("joule"));
+        assert_eq!(Power::from(1.0).unit_name(), Some("watt"));
+        assert_eq!(Charge::from(1.0).unit_name(), Some("coulomb"));
+        assert_eq!(Voltage::from(1.0).unit_name(), Some("volt"));
+        assert_eq!(Resistance::from(1.0).unit_name(), Some("ohm"));
+        assert_eq!(Capacitance::from(1.0).unit_name(), Some("farad"));
+        assert_eq!(Velocity::from(1.0).unit_name(), None);
+        assert_eq!(Torque::from(1.0).unit_name(), None);
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{Dimensionless, Quantity, Scalar, Unit};
+use num_traits::{One, Zero};
+
+impl<const UNIT: Unit, S: Scalar> Zero for Quantity<UNIT, S> {
+    fn zero() -> Self {
+        Self(S::ZERO)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == S::ZERO
+    }
+}
+
+// Multiplying two quantities changes the unit unless both are dimensionless,
+// so only `Dimensionless` has a multiplicative identity. The `Mul` output only
+// normalizes to `Dimensionless` for a concrete storage type, hence the macro.
+macro_rules! one {
+    ($t: ty) => {
+        impl One for Dimensionless<$t> {
+            fn one() -> Self {
+                Self(<$t>::ONE)
+            }
+        }
+    };
+}
+
+one!(f32);
+one!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use num_traits::{One, Zero};
+
+    #[test]
+    fn zero() {
+        assert_eq!(Force::zero(), Force::from(0.0));
+        let zero: Force = Zero::zero();
+        assert!(zero.is_zero());
+        assert!(!Force::from(1.0).is_zero());
+
+        let forces = [Force::from(1.0), Force::from(2.0)];
+        let total = forces.iter().fold(Force::zero(), |acc, &f| acc + f);
+        assert_eq!(total, Force::from(3.0));
+    }
+
+    #[test]
+    fn one() {
+        assert_eq!(Dimensionless::one(), Dimensionless::from(1.0));
+        assert!(Dimensionless::from(1.0).is_one());
+    }
+}
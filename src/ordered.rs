@@ -0,0 +1,64 @@
+use crate::{Quantity, Unit};
+use core::hash::{Hash, Hasher};
+
+// Compares and hashes the bit pattern of the value, so that quantities can be
+// used as `HashMap` keys. `Quantity` itself can't be `Hash`: `0.0 == -0.0`
+// although their bits differ. Here they are different keys, and a NaN equals
+// itself if the bits match.
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedQuantity<const UNIT: Unit, S = f64>(pub Quantity<UNIT, S>);
+
+macro_rules! ordered {
+    ($($t: ty),*) => {
+        $(
+            impl<const UNIT: Unit> PartialEq for OrderedQuantity<UNIT, $t> {
+                fn eq(&self, other: &Self) -> bool {
+                    self.0.value().to_bits() == other.0.value().to_bits()
+                }
+            }
+
+            impl<const UNIT: Unit> Eq for OrderedQuantity<UNIT, $t> {}
+
+            impl<const UNIT: Unit> Hash for OrderedQuantity<UNIT, $t> {
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    self.0.value().to_bits().hash(state);
+                    UNIT.hash(state);
+                }
+            }
+        )*
+    };
+}
+
+ordered!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn hash_map() {
+        let mut areas = HashMap::new();
+        for side in [1.0, 2.0, 3.5] {
+            let side = Length::from(side);
+            areas.insert(OrderedQuantity(side), side * side);
+        }
+        assert_eq!(areas.len(), 3);
+        assert_eq!(areas[&OrderedQuantity(Length::from(2.0))], Area::from(4.0));
+        assert_eq!(
+            areas[&OrderedQuantity(Length::from(3.5))],
+            Area::from(12.25)
+        );
+        assert!(!areas.contains_key(&OrderedQuantity(Length::from(4.0))));
+    }
+
+    #[test]
+    fn bitwise() {
+        assert_ne!(
+            OrderedQuantity(Length::from(0.0)),
+            OrderedQuantity(Length::from(-0.0))
+        );
+        let nan = OrderedQuantity(Length::from(f64::NAN));
+        assert_eq!(nan, nan);
+    }
+}
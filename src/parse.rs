@@ -0,0 +1,303 @@
+use crate::named::NAMED_UNITS;
+use crate::{DimensionError, DynQuantity, Quantity, Ratio, Scalar, Unit};
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseQuantityError {
+    MissingValue,
+    InvalidValue(String),
+    UnknownSymbol(String),
+    DuplicateSymbol(String),
+    InvalidExponent(String),
+    DanglingOperator(char),
+    ExponentOverflow,
+    UnitMismatch(DimensionError),
+}
+
+impl core::fmt::Display for ParseQuantityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MissingValue => write!(f, "missing value"),
+            Self::InvalidValue(value) => write!(f, "invalid value `{value}`"),
+            Self::UnknownSymbol(symbol) => write!(f, "unknown unit symbol `{symbol}`"),
+            Self::DuplicateSymbol(symbol) => write!(f, "duplicate unit symbol `{symbol}`"),
+            Self::InvalidExponent(exponent) => write!(f, "invalid exponent `{exponent}`"),
+            Self::DanglingOperator(operator) => write!(f, "missing unit after `{operator}`"),
+            Self::ExponentOverflow => write!(f, "unit exponent out of range"),
+            Self::UnitMismatch(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl core::error::Error for ParseQuantityError {}
+
+impl From<DimensionError> for ParseQuantityError {
+    fn from(error: DimensionError) -> Self {
+        Self::UnitMismatch(error)
+    }
+}
+
+// Parses the space separated `symbol^exponent` terms following the value.
+// Terms may appear in any order, a missing `^exponent` means `^1` and missing
+// symbols have a zero exponent, so both `format_units` and `Display` output
+// is accepted.
+fn parse_unit<'a>(terms: impl Iterator<Item = &'a str>) -> Result<Unit, ParseQuantityError> {
+    let symbols = Unit::SYMBOLS;
+    let mut exponents = symbols.map(|_| Ratio::int(0));
+    let mut seen = symbols.map(|_| false);
+    for term in terms {
+        let (symbol, exponent) = term.split_once('^').unwrap_or((term, "1"));
+        let index = symbols
+            .iter()
+            .position(|&s| s == symbol)
+            .ok_or_else(|| ParseQuantityError::UnknownSymbol(symbol.to_owned()))?;
+        if seen[index] {
+            return Err(ParseQuantityError::DuplicateSymbol(symbol.to_owned()));
+        }
+        seen[index] = true;
+        exponents[index] = Ratio::parse(exponent)
+            .ok_or_else(|| ParseQuantityError::InvalidExponent(exponent.to_owned()))?;
+    }
+    Ok(Unit::new(exponents))
+}
+
+impl<const UNIT: Unit, S: Scalar + FromStr> FromStr for Quantity<UNIT, S> {
+    type Err = ParseQuantityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split_whitespace();
+        let value = tokens.next().ok_or(ParseQuantityError::MissingValue)?;
+        let value = value
+            .parse()
+            .map_err(|_| ParseQuantityError::InvalidValue(value.to_owned()))?;
+        let unit = parse_unit(tokens)?;
+        DimensionError::check(UNIT, unit)?;
+        Ok(Self(value))
+    }
+}
+
+// A single `symbol` or `symbol^exponent` factor of a unit expression, where the
+// symbol is either a base unit or a named derived unit such as `N`.
+fn parse_factor(factor: &str) -> Result<Unit, ParseQuantityError> {
+    let (symbol, exponent) = factor.split_once('^').unwrap_or((factor, "1"));
+    let unit = match Unit::SYMBOLS.iter().position(|&s| s == symbol) {
+        Some(index) => {
+            let mut exponents = [0; 6];
+            exponents[index] = 1;
+            Unit::from_integers(exponents)
+        }
+        None => NAMED_UNITS
+            .iter()
+            .find(|(named, _, _)| *named == symbol)
+            .map(|(_, _, unit)| *unit)
+            .ok_or_else(|| ParseQuantityError::UnknownSymbol(symbol.to_owned()))?,
+    };
+    let exponent = Ratio::parse(exponent)
+        .ok_or_else(|| ParseQuantityError::InvalidExponent(exponent.to_owned()))?;
+    unit.checked_scale(exponent.num, exponent.den)
+        .ok_or(ParseQuantityError::ExponentOverflow)
+}
+
+// Unit expressions such as `m/s^2` or `kg m^2 / s^2`. Factors are joined by `*`,
+// `/` or whitespace, which all have the same precedence and are applied left to
+// right, so `J/kg*s` is `J s kg^-1`. Exponents bind tighter than both, and
+// fractional ones need parentheses, e.g. `m^(1/2)`.
+pub(crate) fn parse_expression(mut s: &str) -> Result<Unit, ParseQuantityError> {
+    let mut unit = Unit::DIMENSIONLESS;
+    let mut operator = None;
+    loop {
+        s = s.trim_start();
+        let Some(next) = s.chars().next() else {
+            break;
+        };
+        if next == '*' || next == '/' {
+            if let Some(operator) = operator {
+                return Err(ParseQuantityError::DanglingOperator(operator));
+            }
+            operator = Some(next);
+            s = &s[1..];
+            continue;
+        }
+        let mut depth = 0;
+        let end = s
+            .find(|c: char| {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+                depth == 0 && (c.is_whitespace() || c == '*' || c == '/')
+            })
+            .unwrap_or(s.len());
+        let factor = parse_factor(&s[..end])?;
+        unit = match operator.take() {
+            Some('/') => unit.checked_sub(factor),
+            _ => unit.checked_add(factor),
+        }
+        .ok_or(ParseQuantityError::ExponentOverflow)?;
+        s = &s[end..];
+    }
+    match operator {
+        Some(operator) => Err(ParseQuantityError::DanglingOperator(operator)),
+        None => Ok(unit),
+    }
+}
+
+// A value followed by a unit expression, e.g. `9.8 m/s^2` or `100 N`. Convert
+// the result with `Quantity::try_from` once the expected unit is known.
+impl FromStr for DynQuantity {
+    type Err = ParseQuantityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim_start();
+        let end = s.find(char::is_whitespace).unwrap_or(s.len());
+        let value = &s[..end];
+        if value.is_empty() {
+            return Err(ParseQuantityError::MissingValue);
+        }
+        let value = value
+            .parse()
+            .map_err(|_| ParseQuantityError::InvalidValue(value.to_owned()))?;
+        Ok(DynQuantity::new(value, parse_expression(&s[end..])?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn round_trip() {
+        let force = Force::from(9.8);
+        assert_eq!(force.format_units().parse(), Ok(force));
+
+        let pressure = Pressure::from(-1.5);
+        assert_eq!(pressure.format_units().parse(), Ok(pressure));
+
+        let energy = Energy::from(2.0);
+        assert_eq!(energy.format_units().parse(), Ok(energy));
+
+        let root = Length::from(4.0).sqrt();
+        assert_eq!(root.format_units().parse(), Ok(root));
+    }
+
+    #[test]
+    fn whitespace() {
+        let expected = Ok(Acceleration::from(9.8));
+        assert_eq!("  9.8 m^1   kg^0 s^-2 ".parse(), expected);
+        assert_eq!("9.8\tm\ts^-2".parse(), expected);
+        assert_eq!("9.8 s^-2 m".parse(), expected);
+        assert_eq!("1.0".parse(), Ok(Dimensionless::from(1.0)));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!("".parse::<Length>(), Err(ParseQuantityError::MissingValue));
+        assert_eq!(
+            "m^1".parse::<Length>(),
+            Err(ParseQuantityError::InvalidValue("m^1".to_owned()))
+        );
+        assert_eq!(
+            "1.0 ft".parse::<Length>(),
+            Err(ParseQuantityError::UnknownSymbol("ft".to_owned()))
+        );
+        assert_eq!(
+            "1.0 m m".parse::<Length>(),
+            Err(ParseQuantityError::DuplicateSymbol("m".to_owned()))
+        );
+        assert_eq!(
+            "1.0 m^x".parse::<Length>(),
+            Err(ParseQuantityError::InvalidExponent("x".to_owned()))
+        );
+
+        assert_eq!(
+            "1.0 m^(1/0)".parse::<Length>(),
+            Err(ParseQuantityError::InvalidExponent("(1/0)".to_owned()))
+        );
+        assert_eq!(
+            "1 m^(-2147483648/-1)".parse::<Length>(),
+            Err(ParseQuantityError::InvalidExponent(
+                "(-2147483648/-1)".to_owned()
+            ))
+        );
+
+        let error = "9.8 m^1 kg^0 s^-1".parse::<Acceleration>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected m^1 kg^0 s^-2 A^0 K^0 rad^0 but found m^1 kg^0 s^-1 A^0 K^0 rad^0"
+        );
+    }
+
+    #[test]
+    fn dynamic() {
+        let acceleration: DynQuantity = "9.8 m/s^2".parse().unwrap();
+        assert_eq!(acceleration.value(), 9.8);
+        assert_eq!(acceleration.unit(), Acceleration::<f64>::UNIT);
+        assert_eq!(
+            Acceleration::try_from(acceleration),
+            Ok(Acceleration::from(9.8))
+        );
+
+        let force: DynQuantity = "100 N".parse().unwrap();
+        assert_eq!(Force::try_from(force), Ok(Force::from(100.0)));
+        assert!(Energy::try_from(force).is_err());
+
+        let mass: DynQuantity = "5 kg".parse().unwrap();
+        assert_eq!(mass, DynQuantity::from(Mass::from(5.0)));
+    }
+
+    #[test]
+    fn expressions() {
+        let parse = |s: &str| s.parse::<DynQuantity>().map(DynQuantity::unit);
+        assert_eq!(parse("1 kg*m/s^2"), Ok(Force::<f64>::UNIT));
+        assert_eq!(parse("1 kg m^2 / s^2"), Ok(Energy::<f64>::UNIT));
+        assert_eq!(parse("1 J/kg*s"), parse("1 J s kg^-1"));
+        assert_eq!(parse("1 N m"), Ok(Energy::<f64>::UNIT));
+        assert_eq!(parse("1 W/V"), Ok(Current::<f64>::UNIT));
+        assert_eq!(parse("1 m^(1/2)"), Ok(Length::<f64>::UNIT.half()));
+        assert_eq!(parse("1 /s"), Ok(Frequency::<f64>::UNIT));
+        assert_eq!(parse("1"), Ok(Dimensionless::<f64>::UNIT));
+    }
+
+    #[test]
+    fn expression_errors() {
+        let parse = |s: &str| s.parse::<DynQuantity>();
+        assert_eq!(parse(" "), Err(ParseQuantityError::MissingValue));
+        assert_eq!(
+            parse("1 ft/s"),
+            Err(ParseQuantityError::UnknownSymbol("ft".to_owned()))
+        );
+        assert_eq!(
+            parse("1 m/"),
+            Err(ParseQuantityError::DanglingOperator('/'))
+        );
+        assert_eq!(
+            parse("1 m*/s"),
+            Err(ParseQuantityError::DanglingOperator('*'))
+        );
+        assert_eq!(
+            parse("1 m^1/2"),
+            Err(ParseQuantityError::UnknownSymbol("2".to_owned()))
+        );
+        assert_eq!(
+            parse("1 m^x"),
+            Err(ParseQuantityError::InvalidExponent("x".to_owned()))
+        );
+        assert_eq!(
+            parse("1 m^(-2147483648/-1)"),
+            Err(ParseQuantityError::InvalidExponent(
+                "(-2147483648/-1)".to_owned()
+            ))
+        );
+        for overflow in [
+            "1 m^2147483647 m",
+            "1 m^(1/65536) m^(1/65537)",
+            "1 /m^-2147483648",
+            "1 N^2147483647",
+        ] {
+            assert_eq!(parse(overflow), Err(ParseQuantityError::ExponentOverflow));
+        }
+    }
+}
@@ -0,0 +1,193 @@
+#[cfg(feature = "std")]
+use crate::Mass;
+use crate::{Quantity, Scalar, Unit};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Prefix {
+    Femto,
+    Pico,
+    Nano,
+    Micro,
+    Milli,
+    Centi,
+    Deci,
+    Kilo,
+    Mega,
+    Giga,
+    Tera,
+    Peta,
+}
+
+impl Prefix {
+    pub const fn exponent(self) -> i32 {
+        match self {
+            Self::Femto => -15,
+            Self::Pico => -12,
+            Self::Nano => -9,
+            Self::Micro => -6,
+            Self::Milli => -3,
+            Self::Centi => -2,
+            Self::Deci => -1,
+            Self::Kilo => 3,
+            Self::Mega => 6,
+            Self::Giga => 9,
+            Self::Tera => 12,
+            Self::Peta => 15,
+        }
+    }
+
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Femto => "f",
+            Self::Pico => "p",
+            Self::Nano => "n",
+            Self::Micro => "µ",
+            Self::Milli => "m",
+            Self::Centi => "c",
+            Self::Deci => "d",
+            Self::Kilo => "k",
+            Self::Mega => "M",
+            Self::Giga => "G",
+            Self::Tera => "T",
+            Self::Peta => "P",
+        }
+    }
+
+    // Powers of ten up to 1e22 are exact in f64, so scaling by multiplying with
+    // or dividing by one keeps the result correctly rounded.
+    pub fn apply(self, value: f64) -> f64 {
+        let exponent = self.exponent();
+        let factor = (0..exponent.abs()).fold(1.0, |factor, _| factor * 10.0);
+        if exponent < 0 {
+            value / factor
+        } else {
+            value * factor
+        }
+    }
+}
+
+// Prefixes whose exponents are multiples of three, in ascending order, with
+// `None` standing in for the unprefixed unit.
+#[cfg(feature = "std")]
+const ENGINEERING: [Option<Prefix>; 11] = [
+    Some(Prefix::Femto),
+    Some(Prefix::Pico),
+    Some(Prefix::Nano),
+    Some(Prefix::Micro),
+    Some(Prefix::Milli),
+    None,
+    Some(Prefix::Kilo),
+    Some(Prefix::Mega),
+    Some(Prefix::Giga),
+    Some(Prefix::Tera),
+    Some(Prefix::Peta),
+];
+
+macro_rules! prefixed {
+    ($($name: ident => $prefix: ident),* $(,)?) => {
+        $(
+            pub fn $name(value: S) -> Self {
+                Self::scaled(value, Prefix::$prefix)
+            }
+        )*
+    };
+}
+
+// Values are always stored in SI base units. The prefix scales the base unit,
+// so for `Mass` it applies to kilograms rather than grams.
+impl<const UNIT: Unit, S: Scalar> Quantity<UNIT, S> {
+    pub fn scaled(value: S, prefix: Prefix) -> Self {
+        Self::from_base_f64(prefix.apply(value.to_f64()))
+    }
+
+    prefixed!(
+        from_femto => Femto,
+        from_pico => Pico,
+        from_nano => Nano,
+        from_micro => Micro,
+        from_milli => Milli,
+        from_centi => Centi,
+        from_deci => Deci,
+        from_kilo => Kilo,
+        from_mega => Mega,
+        from_giga => Giga,
+        from_tera => Tera,
+        from_peta => Peta,
+    );
+
+    // Picks the prefix that puts the mantissa in `[1, 1000)`, clamping to the
+    // smallest and largest available prefix. Mass is rendered in grams, and
+    // compound units are parenthesized so the prefix applies to the whole unit.
+    #[cfg(feature = "std")]
+    pub fn format_engineering(self) -> String {
+        let mut value = self.to_base_f64();
+        let mut symbol = UNIT.to_string();
+        if UNIT == Mass::<S>::UNIT {
+            value *= 1000.0;
+            symbol = "g".to_owned();
+        }
+        if UNIT == Unit::DIMENSIONLESS || value == 0.0 || !value.is_finite() {
+            return format!("{value:0.1} {symbol}").trim_end().to_owned();
+        }
+
+        let last = ENGINEERING.len() - 1;
+        let base = ENGINEERING.iter().position(Option::is_none).unwrap();
+        let exponent = value.abs().log10().floor() as i32;
+        let mut index = (base as i32 + exponent.div_euclid(3)).clamp(0, last as i32) as usize;
+        let mut mantissa = scale(value, ENGINEERING[index]);
+        if (mantissa * 10.0).round().abs() >= 10000.0 && index < last {
+            index += 1;
+            mantissa = scale(value, ENGINEERING[index]);
+        }
+
+        match ENGINEERING[index] {
+            None => format!("{mantissa:0.1} {symbol}"),
+            Some(prefix) if symbol.contains(' ') || symbol.contains('^') => {
+                format!("{mantissa:0.1} {}({symbol})", prefix.symbol())
+            }
+            Some(prefix) => format!("{mantissa:0.1} {}{symbol}", prefix.symbol()),
+        }
+    }
+}
+
+// Expresses an SI base value as a multiple of the prefixed unit.
+#[cfg(feature = "std")]
+fn scale(value: f64, prefix: Option<Prefix>) -> f64 {
+    match prefix {
+        Some(prefix) => value / prefix.apply(1.0),
+        None => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn scaled() {
+        assert_eq!(Length::from_milli(5.0), Length::from(0.005));
+        assert_eq!(Time::from_micro(3.0), Time::from(0.000003));
+        assert_eq!(Power::from_mega(2.5), Power::from(2_500_000.0));
+        assert_eq!(Mass::from_kilo(2.0), Mass::from(2000.0));
+        assert_eq!(Frequency::scaled(1.0, Prefix::Giga), Frequency::from(1e9));
+    }
+
+    #[test]
+    fn engineering() {
+        assert_eq!(Length::from(0.0005).format_engineering(), "500.0 µm");
+        assert_eq!(Length::from(1.0).format_engineering(), "1.0 m");
+        assert_eq!(Length::from(-2500.0).format_engineering(), "-2.5 km");
+        assert_eq!(Length::from(0.0).format_engineering(), "0.0 m");
+        assert_eq!(Time::from(3e-9).format_engineering(), "3.0 ns");
+        assert_eq!(Frequency::from(2.4e9).format_engineering(), "2.4 G(s^-1)");
+        assert_eq!(
+            Power::from(2e18).format_engineering(),
+            "2000.0 P(m^2 kg s^-3)"
+        );
+        assert_eq!(Length::from(1e-20).format_engineering(), "0.0 fm");
+        assert_eq!(Length::from(999.99).format_engineering(), "1.0 km");
+        assert_eq!(Mass::from(0.0005).format_engineering(), "500.0 mg");
+        assert_eq!(Mass::from(2.0).format_engineering(), "2.0 kg");
+        assert_eq!(Dimensionless::from(0.5).format_engineering(), "0.5");
+    }
+}
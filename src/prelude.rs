@@ -0,0 +1,24 @@
+//! Common types and traits, for glob imports.
+//!
+//! ```
+//! #![allow(incomplete_features)]
+//! #![feature(generic_const_exprs, adt_const_params)]
+//! use p3d_si::prelude::*;
+//!
+//! let force: Force = 2.0.kilograms() * 10.0.meters_per_second_squared();
+//! let work: Energy = force * Length::from(3.0);
+//! assert_eq!(work, 60.0.joules());
+//! ```
+
+pub use crate::{Float, Quantity, Scalar, Si, Unit, Vec3};
+
+pub use crate::{Angle, Current, Dimensionless, Length, Mass, Temperature, Time};
+
+pub use crate::{
+    Acceleration, Action, AngularVelocity, Area, Capacitance, Charge, Curvature, Density,
+    Diffusivity, DynamicViscosity, Energy, EntropyRate, Force, Frequency, HeatCapacity, Impulse,
+    Jerk, KinematicViscosity, MassFlowRate, Momentum, Power, Pressure, Resistance, Snap,
+    SpecificEnergy, SpecificVolume, SpringConstant, Stiffness, SurfaceTension,
+    TemperatureDifference, TemperatureGradient, ThermalConductivity, ThermalExpansionCoefficient,
+    Torque, Velocity, Voltage, Volume, VolumetricFlowRate, Wavenumber,
+};
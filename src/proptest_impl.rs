@@ -0,0 +1,43 @@
+use crate::{Quantity, Unit};
+use core::ops::RangeInclusive;
+use proptest::strategy::Strategy;
+
+impl<const UNIT: Unit> Quantity<UNIT> {
+    // Generates finite quantities within `range`, for use with `proptest!`.
+    pub fn strategy(range: RangeInclusive<Self>) -> impl Strategy<Value = Self> {
+        let (start, end) = range.into_inner();
+        (start.0..=end.0).prop_map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use proptest::prelude::*;
+
+    fn force() -> impl Strategy<Value = Force> {
+        Force::strategy(Force::from(-1e6)..=Force::from(1e6))
+    }
+
+    fn close(a: Force, b: Force) -> bool {
+        a.relative_eq(b, Force::from(1e-9), Dimensionless::from(1e-12))
+    }
+
+    proptest! {
+        #[test]
+        fn in_range(a in force()) {
+            prop_assert!(a.is_finite());
+            prop_assert!(a.abs() <= Force::from(1e6));
+        }
+
+        #[test]
+        fn commutative(a in force(), b in force()) {
+            prop_assert_eq!(a + b, b + a);
+        }
+
+        #[test]
+        fn associative(a in force(), b in force(), c in force()) {
+            prop_assert!(close((a + b) + c, a + (b + c)));
+        }
+    }
+}
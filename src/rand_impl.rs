@@ -0,0 +1,46 @@
+use crate::{Quantity, Unit};
+use rand::distributions::Distribution;
+use rand::Rng;
+use rand_distr::{Normal, NormalError};
+
+// Normal distribution over quantities of one unit. Taking the mean and the
+// standard deviation as quantities keeps both in the unit of the samples.
+#[derive(Clone, Copy, Debug)]
+pub struct StandardQuantity<const UNIT: Unit> {
+    normal: Normal<f64>,
+}
+
+impl<const UNIT: Unit> StandardQuantity<UNIT> {
+    pub fn new(mean: Quantity<UNIT>, std_dev: Quantity<UNIT>) -> Result<Self, NormalError> {
+        let normal = Normal::new(mean.0, std_dev.0)?;
+        Ok(Self { normal })
+    }
+}
+
+impl<const UNIT: Unit> Distribution<Quantity<UNIT>> for StandardQuantity<UNIT> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quantity<UNIT> {
+        Quantity(self.normal.sample(rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn mean() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let noise = StandardQuantity::new(Length::from(5.0), Length::from(0.5)).unwrap();
+        let count = 10_000;
+        let total: Length = (0..count).map(|_| rng.sample(noise)).sum();
+        let mean = total / count as f64;
+        assert!(mean.abs_diff_eq(Length::from(5.0), Length::from(0.05)));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(StandardQuantity::new(Length::from(0.0), Length::from(f64::INFINITY)).is_err());
+    }
+}
@@ -0,0 +1,224 @@
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display};
+use core::ops::*;
+
+// Storage type of a `Quantity`. Only the arithmetic every representation
+// supports lives here, so `Quantity` operators work for any of them.
+pub trait Scalar:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+}
+
+// Floating point storage types, which additionally support the usual math
+// functions from the standard library. Most of them are missing from `core`,
+// so without the `std` feature `f32` and `f64` don't implement this.
+pub trait Float: Scalar {
+    fn sqrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+    fn is_sign_negative(self) -> bool;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn clamp(self, lo: Self, hi: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn log10(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn is_infinite(self) -> bool;
+    fn round(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn trunc(self) -> Self;
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+    fn recip(self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn total_cmp(&self, other: &Self) -> Ordering;
+    fn hypot(self, other: Self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+    fn fract(self) -> Self;
+    fn midpoint(self, other: Self) -> Self;
+}
+
+macro_rules! float {
+    ($t: ty) => {
+        impl Scalar for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl Float for $t {
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+
+            fn powi(self, n: i32) -> Self {
+                <$t>::powi(self, n)
+            }
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+
+            fn is_sign_negative(self) -> bool {
+                <$t>::is_sign_negative(self)
+            }
+
+            fn min(self, other: Self) -> Self {
+                <$t>::min(self, other)
+            }
+
+            fn max(self, other: Self) -> Self {
+                <$t>::max(self, other)
+            }
+
+            fn clamp(self, lo: Self, hi: Self) -> Self {
+                <$t>::clamp(self, lo, hi)
+            }
+
+            fn sin(self) -> Self {
+                <$t>::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                <$t>::cos(self)
+            }
+
+            fn tan(self) -> Self {
+                <$t>::tan(self)
+            }
+
+            fn asin(self) -> Self {
+                <$t>::asin(self)
+            }
+
+            fn acos(self) -> Self {
+                <$t>::acos(self)
+            }
+
+            fn atan(self) -> Self {
+                <$t>::atan(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                <$t>::atan2(self, other)
+            }
+
+            fn exp(self) -> Self {
+                <$t>::exp(self)
+            }
+
+            fn ln(self) -> Self {
+                <$t>::ln(self)
+            }
+
+            fn log10(self) -> Self {
+                <$t>::log10(self)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                <$t>::powf(self, n)
+            }
+
+            fn is_infinite(self) -> bool {
+                <$t>::is_infinite(self)
+            }
+
+            fn round(self) -> Self {
+                <$t>::round(self)
+            }
+
+            fn floor(self) -> Self {
+                <$t>::floor(self)
+            }
+
+            fn ceil(self) -> Self {
+                <$t>::ceil(self)
+            }
+
+            fn trunc(self) -> Self {
+                <$t>::trunc(self)
+            }
+
+            fn is_nan(self) -> bool {
+                <$t>::is_nan(self)
+            }
+
+            fn is_finite(self) -> bool {
+                <$t>::is_finite(self)
+            }
+
+            fn recip(self) -> Self {
+                <$t>::recip(self)
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                <$t>::mul_add(self, a, b)
+            }
+
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                <$t>::total_cmp(self, other)
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                <$t>::hypot(self, other)
+            }
+
+            fn copysign(self, sign: Self) -> Self {
+                <$t>::copysign(self, sign)
+            }
+
+            fn fract(self) -> Self {
+                <$t>::fract(self)
+            }
+
+            fn midpoint(self, other: Self) -> Self {
+                <$t>::midpoint(self, other)
+            }
+        }
+    };
+}
+
+float!(f32);
+float!(f64);
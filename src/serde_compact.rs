@@ -0,0 +1,96 @@
+// Compact serde representation as a `[value, "unit"]` pair, e.g.
+// `[9.8, "m s^-2"]`, for use with `#[serde(with = "p3d_si::serde_compact")]`.
+// The unit is written like `Display` does and read with the same syntax as
+// `DynQuantity::from_str`, so hand-edited files may use `m/s^2` or `N` as
+// well. Deserializing fails unless the unit matches the field's type.
+use crate::parse::parse_expression;
+use crate::{DimensionError, Quantity, Scalar, Unit};
+use alloc::string::{String, ToString};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+pub fn serialize<const UNIT: Unit, S: Scalar + Serialize, Z: Serializer>(
+    quantity: &Quantity<UNIT, S>,
+    serializer: Z,
+) -> Result<Z::Ok, Z::Error> {
+    (quantity.0, UNIT.to_string()).serialize(serializer)
+}
+
+pub fn deserialize<'de, const UNIT: Unit, S: Scalar + Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Quantity<UNIT, S>, D::Error> {
+    let (value, unit) = <(S, String)>::deserialize(deserializer)?;
+    let unit = parse_expression(&unit).map_err(de::Error::custom)?;
+    DimensionError::check(UNIT, unit).map_err(de::Error::custom)?;
+    Ok(Quantity(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Config {
+        #[serde(with = "crate::serde_compact")]
+        gravity: Acceleration,
+        #[serde(with = "crate::serde_compact")]
+        thrust: Force,
+        #[serde(with = "crate::serde_compact")]
+        efficiency: Dimensionless,
+    }
+
+    fn config() -> Config {
+        Config {
+            gravity: Acceleration::from(9.8),
+            thrust: Force::from(1500.0),
+            efficiency: Dimensionless::from(0.5),
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let json = serde_json::to_string(&config()).unwrap();
+        assert_eq!(
+            json,
+            r#"{"gravity":[9.8,"m s^-2"],"thrust":[1500.0,"m kg s^-2"],"efficiency":[0.5,""]}"#
+        );
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config());
+    }
+
+    #[test]
+    fn hand_written() {
+        let json = r#"{"gravity":[9.8,"m/s^2"],"thrust":[1500.0,"N"],"efficiency":[0.5,""]}"#;
+        assert_eq!(serde_json::from_str::<Config>(json).unwrap(), config());
+    }
+
+    #[test]
+    fn mismatch() {
+        let json = r#"{"gravity":[9.8,"m/s"],"thrust":[1500.0,"N"],"efficiency":[0.5,""]}"#;
+        let error = serde_json::from_str::<Config>(json).unwrap_err();
+        assert!(error.to_string().contains(
+            "expected m^1 kg^0 s^-2 A^0 K^0 rad^0 but found m^1 kg^0 s^-1 A^0 K^0 rad^0"
+        ));
+
+        let json = r#"{"gravity":[9.8,"ft/s^2"],"thrust":[1500.0,"N"],"efficiency":[0.5,""]}"#;
+        let error = serde_json::from_str::<Config>(json).unwrap_err();
+        assert!(error.to_string().contains("unknown unit symbol `ft`"));
+    }
+
+    #[test]
+    fn overflow() {
+        for unit in [
+            "m^(-2147483648/-1)",
+            "m^2147483647 m",
+            "m^(1/65536) m^(1/65537)",
+            "/m^-2147483648",
+        ] {
+            let json =
+                format!(r#"{{"gravity":[9.8,"{unit}"],"thrust":[1.0,"N"],"efficiency":[0.5,""]}}"#);
+            assert!(serde_json::from_str::<Config>(&json).is_err(), "{unit}");
+        }
+        let json = r#"{"gravity":[9.8,"m^2147483647 m"],"thrust":[1.0,"N"],"efficiency":[0.5,""]}"#;
+        let error = serde_json::from_str::<Config>(json).unwrap_err();
+        assert!(error.to_string().contains("unit exponent out of range"));
+    }
+}
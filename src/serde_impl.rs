@@ -0,0 +1,161 @@
+use crate::{DimensionError, Quantity, Ratio, Scalar, Unit};
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+// Integral exponents serialize as plain integers, fractional ones as a
+// `"num/den"` string.
+impl Serialize for Ratio {
+    fn serialize<Z: Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+        if self.den == 1 {
+            serializer.serialize_i32(self.num)
+        } else {
+            serializer.collect_str(&format_args!("{}/{}", self.num, self.den))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Ratio {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct RatioVisitor;
+
+        impl Visitor<'_> for RatioVisitor {
+            type Value = Ratio;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("an integer or a \"num/den\" string")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Ratio, E> {
+                i32::try_from(value)
+                    .map(Ratio::int)
+                    .map_err(|_| E::custom("exponent out of range"))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Ratio, E> {
+                i32::try_from(value)
+                    .map(Ratio::int)
+                    .map_err(|_| E::custom("exponent out of range"))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Ratio, E> {
+                Ratio::parse(value)
+                    .ok_or_else(|| E::custom(format_args!("invalid exponent `{value}`")))
+            }
+        }
+
+        deserializer.deserialize_any(RatioVisitor)
+    }
+}
+
+// Units keep serializing as a struct with one named field per dimension, which
+// is readable and independent of the dimension order.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Unit")]
+struct UnitRepr {
+    length: Ratio,
+    mass: Ratio,
+    time: Ratio,
+    current: Ratio,
+    temperature: Ratio,
+    angle: Ratio,
+}
+
+impl Serialize for Unit {
+    fn serialize<Z: Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+        let [length, mass, time, current, temperature, angle] = self.exponents();
+        let repr = UnitRepr {
+            length,
+            mass,
+            time,
+            current,
+            temperature,
+            angle,
+        };
+        repr.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Unit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = UnitRepr::deserialize(deserializer)?;
+        Ok(Unit::new([
+            repr.length,
+            repr.mass,
+            repr.time,
+            repr.current,
+            repr.temperature,
+            repr.angle,
+        ]))
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar + Serialize> Serialize for Quantity<UNIT, S> {
+    fn serialize<Z: Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+        let mut state = serializer.serialize_struct("Quantity", 2)?;
+        state.serialize_field("value", &self.0)?;
+        state.serialize_field("unit", &UNIT)?;
+        state.end()
+    }
+}
+
+impl<'de, const UNIT: Unit, S: Scalar + Deserialize<'de>> Deserialize<'de> for Quantity<UNIT, S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "Quantity")]
+        struct Repr<S> {
+            value: S,
+            unit: Unit,
+        }
+
+        let repr = Repr::<S>::deserialize(deserializer)?;
+        DimensionError::check(UNIT, repr.unit).map_err(de::Error::custom)?;
+        Ok(Self(repr.value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn round_trip() {
+        let acceleration = Acceleration::from(9.8);
+        let json = serde_json::to_string(&acceleration).unwrap();
+        assert_eq!(
+            json,
+            r#"{"value":9.8,"unit":{"length":1,"mass":0,"time":-2,"current":0,"temperature":0,"angle":0}}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Acceleration>(&json).unwrap(),
+            acceleration
+        );
+
+        let root = Length::from(4.0).sqrt();
+        let json = serde_json::to_string(&root).unwrap();
+        assert!(json.contains(r#""length":"1/2""#));
+        let parsed: Quantity<{ Length::<f64>::UNIT.half() }> = serde_json::from_str(&json).unwrap();
+        assert_eq!(root, parsed);
+    }
+
+    #[test]
+    fn mismatch() {
+        let json = serde_json::to_string(&Velocity::from(9.8)).unwrap();
+        let error = serde_json::from_str::<Acceleration>(&json).unwrap_err();
+        assert!(error.to_string().contains(
+            "expected m^1 kg^0 s^-2 A^0 K^0 rad^0 but found m^1 kg^0 s^-1 A^0 K^0 rad^0"
+        ));
+    }
+
+    #[test]
+    fn invalid_exponent() {
+        let json = r#"{"length":"-2147483648/-1","mass":0,"time":0,"current":0,"temperature":0,"angle":0}"#;
+        let error = serde_json::from_str::<Unit>(json).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("invalid exponent `-2147483648/-1`"));
+
+        let json = r#"{"length":"1/0","mass":0,"time":0,"current":0,"temperature":0,"angle":0}"#;
+        assert!(serde_json::from_str::<Unit>(json).is_err());
+    }
+}
@@ -0,0 +1,123 @@
+use crate::*;
+
+// Ergonomic construction from plain numbers, e.g. `5.0.meters()`. This is only
+// implemented for `f64`: with a second implementation, method calls on
+// unsuffixed float literals would become ambiguous.
+pub trait Si: Sized {
+    fn meters(self) -> Length;
+    fn kilograms(self) -> Mass;
+    fn seconds(self) -> Time;
+    fn amperes(self) -> Current;
+    fn kelvins(self) -> Temperature;
+    fn radians(self) -> Angle;
+    fn square_meters(self) -> Area;
+    fn reciprocal_meters(self) -> Wavenumber;
+    fn cubic_meters(self) -> Volume;
+    fn meters_per_second(self) -> Velocity;
+    fn meters_per_second_squared(self) -> Acceleration;
+    fn meters_per_second_cubed(self) -> Jerk;
+    fn meters_per_second_to_the_fourth(self) -> Snap;
+    fn newtons(self) -> Force;
+    fn hertz(self) -> Frequency;
+    fn pascals(self) -> Pressure;
+    fn newtons_per_meter(self) -> SurfaceTension;
+    fn joules(self) -> Energy;
+    fn joules_per_kilogram(self) -> SpecificEnergy;
+    fn watts(self) -> Power;
+    fn coulombs(self) -> Charge;
+    fn volts(self) -> Voltage;
+    fn ohms(self) -> Resistance;
+    fn farads(self) -> Capacitance;
+    fn joules_per_kelvin(self) -> HeatCapacity;
+    fn watts_per_meter_kelvin(self) -> ThermalConductivity;
+    fn watts_per_kelvin(self) -> EntropyRate;
+    fn radians_per_second(self) -> AngularVelocity;
+    // Torque is tracked per radian, see `Unit`.
+    fn newton_meters_per_radian(self) -> Torque;
+    fn kilogram_meters_per_second(self) -> Momentum;
+    fn newton_seconds(self) -> Impulse;
+    fn joule_seconds(self) -> Action;
+    fn kilograms_per_cubic_meter(self) -> Density;
+    fn cubic_meters_per_kilogram(self) -> SpecificVolume;
+    fn kilograms_per_second(self) -> MassFlowRate;
+    fn cubic_meters_per_second(self) -> VolumetricFlowRate;
+    fn pascal_seconds(self) -> DynamicViscosity;
+    fn square_meters_per_second(self) -> KinematicViscosity;
+    fn per_kelvin(self) -> ThermalExpansionCoefficient;
+    fn kelvins_per_meter(self) -> TemperatureGradient;
+}
+
+macro_rules! si {
+    ($($name: ident => $quantity: ident),* $(,)?) => {
+        impl Si for f64 {
+            $(
+                fn $name(self) -> $quantity {
+                    Quantity(self)
+                }
+            )*
+        }
+    };
+}
+
+si!(
+    meters => Length,
+    kilograms => Mass,
+    seconds => Time,
+    amperes => Current,
+    kelvins => Temperature,
+    radians => Angle,
+    square_meters => Area,
+    reciprocal_meters => Wavenumber,
+    cubic_meters => Volume,
+    meters_per_second => Velocity,
+    meters_per_second_squared => Acceleration,
+    meters_per_second_cubed => Jerk,
+    meters_per_second_to_the_fourth => Snap,
+    newtons => Force,
+    hertz => Frequency,
+    pascals => Pressure,
+    newtons_per_meter => SurfaceTension,
+    joules => Energy,
+    joules_per_kilogram => SpecificEnergy,
+    watts => Power,
+    coulombs => Charge,
+    volts => Voltage,
+    ohms => Resistance,
+    farads => Capacitance,
+    joules_per_kelvin => HeatCapacity,
+    watts_per_meter_kelvin => ThermalConductivity,
+    watts_per_kelvin => EntropyRate,
+    radians_per_second => AngularVelocity,
+    newton_meters_per_radian => Torque,
+    kilogram_meters_per_second => Momentum,
+    newton_seconds => Impulse,
+    joule_seconds => Action,
+    kilograms_per_cubic_meter => Density,
+    cubic_meters_per_kilogram => SpecificVolume,
+    kilograms_per_second => MassFlowRate,
+    cubic_meters_per_second => VolumetricFlowRate,
+    pascal_seconds => DynamicViscosity,
+    square_meters_per_second => KinematicViscosity,
+    per_kelvin => ThermalExpansionCoefficient,
+    kelvins_per_meter => TemperatureGradient,
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn construction() {
+        assert_eq!(5.0.meters(), Length::from(5.0));
+        assert_eq!(2.0.kilograms(), Mass::from(2.0));
+        assert_eq!(3.0.newtons(), Force::from(3.0));
+        assert_eq!(4.0.joules(), Energy::from(4.0));
+    }
+
+    #[test]
+    fn compound() {
+        let acceleration = 9.8.meters() / 1.0.seconds() / 1.0.seconds();
+        assert_eq!(acceleration, 9.8.meters_per_second_squared());
+        assert_eq!(5.0.meters() / 2.0.seconds(), 2.5.meters_per_second());
+    }
+}
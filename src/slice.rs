@@ -0,0 +1,117 @@
+// Element-wise operations over slices of quantities, written as plain loops
+// over the storage values so that they autovectorize. All slices must have the
+// same length.
+use crate::{Quantity, Scalar, Unit};
+
+pub fn add_slices<const UNIT: Unit, S: Scalar>(
+    a: &[Quantity<UNIT, S>],
+    b: &[Quantity<UNIT, S>],
+    out: &mut [Quantity<UNIT, S>],
+) {
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.len(), out.len());
+    for ((out, a), b) in out.iter_mut().zip(a).zip(b) {
+        out.0 = a.0 + b.0;
+    }
+}
+
+pub fn sub_slices<const UNIT: Unit, S: Scalar>(
+    a: &[Quantity<UNIT, S>],
+    b: &[Quantity<UNIT, S>],
+    out: &mut [Quantity<UNIT, S>],
+) {
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.len(), out.len());
+    for ((out, a), b) in out.iter_mut().zip(a).zip(b) {
+        out.0 = a.0 - b.0;
+    }
+}
+
+pub fn scale_slice<const UNIT: Unit, S: Scalar>(
+    a: &[Quantity<UNIT, S>],
+    factor: S,
+    out: &mut [Quantity<UNIT, S>],
+) {
+    assert_eq!(a.len(), out.len());
+    for (out, a) in out.iter_mut().zip(a) {
+        out.0 = a.0 * factor;
+    }
+}
+
+// Sum of element-wise products, e.g. forces along a path dotted with the
+// displacements gives the work done.
+pub fn dot<const A: Unit, const B: Unit, S: Scalar>(
+    xs: &[Quantity<A, S>],
+    ys: &[Quantity<B, S>],
+) -> Quantity<{ A.add(B) }, S>
+where
+    Quantity<{ A.add(B) }, S>: Sized,
+{
+    assert_eq!(xs.len(), ys.len());
+    let mut sum = S::ZERO;
+    for (x, y) in xs.iter().zip(ys) {
+        sum += x.0 * y.0;
+    }
+    Quantity(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Energy, Force, Length};
+
+    fn lengths(n: usize, offset: f64) -> Vec<Length> {
+        (0..n).map(|i| Length::from(i as f64 + offset)).collect()
+    }
+
+    #[test]
+    fn add() {
+        let a = lengths(37, 0.5);
+        let b = lengths(37, 1.0);
+        let mut out = vec![Length::default(); 37];
+        add_slices(&a, &b, &mut out);
+        let expected: Vec<_> = a.iter().zip(&b).map(|(&a, &b)| a + b).collect();
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn sub() {
+        let a = lengths(37, 0.5);
+        let b = lengths(37, 1.0);
+        let mut out = vec![Length::default(); 37];
+        sub_slices(&a, &b, &mut out);
+        let expected: Vec<_> = a.iter().zip(&b).map(|(&a, &b)| a - b).collect();
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn scale() {
+        let a = lengths(37, 0.5);
+        let mut out = vec![Length::default(); 37];
+        scale_slice(&a, 3.0, &mut out);
+        let expected: Vec<_> = a.iter().map(|&a| a * 3.0).collect();
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_lengths() {
+        let mut out = vec![Length::default(); 2];
+        add_slices(&lengths(2, 0.0), &lengths(3, 0.0), &mut out);
+    }
+
+    #[test]
+    fn work() {
+        let forces = [Force::from(2.0), Force::from(3.0), Force::from(-1.0)];
+        let displacements = [Length::from(1.0), Length::from(0.5), Length::from(4.0)];
+        let work = dot(&forces, &displacements);
+        // Comparing against an `Energy` only compiles if the units match.
+        assert_eq!(work, Energy::from(-0.5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn dot_mismatched_lengths() {
+        dot(&lengths(2, 0.0), &lengths(3, 0.0));
+    }
+}
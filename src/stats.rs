@@ -0,0 +1,49 @@
+// Statistics over slices of quantities. Squared deviations carry the squared
+// unit, so the variance of lengths is an area and its square root a length.
+use crate::{Quantity, Scalar, Unit};
+
+pub fn mean<const UNIT: Unit, S: Scalar>(slice: &[Quantity<UNIT, S>]) -> Option<Quantity<UNIT, S>> {
+    if slice.is_empty() {
+        return None;
+    }
+    let sum: Quantity<UNIT, S> = slice.iter().sum();
+    Some(sum / S::from_f64(slice.len() as f64))
+}
+
+// Population variance, i.e. the mean squared deviation from the mean.
+pub fn variance<const UNIT: Unit, S: Scalar>(
+    slice: &[Quantity<UNIT, S>],
+) -> Option<Quantity<{ UNIT.add(UNIT) }, S>>
+where
+    Quantity<{ UNIT.add(UNIT) }, S>: Sized,
+{
+    let mean = mean(slice)?;
+    let mut sum = S::ZERO;
+    for quantity in slice {
+        let deviation = quantity.0 - mean.0;
+        sum += deviation * deviation;
+    }
+    Some(Quantity(sum / S::from_f64(slice.len() as f64)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn mean() {
+        let lengths = [Length::from(1.0), Length::from(2.0), Length::from(3.0)];
+        assert_eq!(stats::mean(&lengths), Some(Length::from(2.0)));
+        assert_eq!(stats::mean::<{ Length::<f64>::UNIT }, f64>(&[]), None);
+    }
+
+    #[test]
+    fn variance() {
+        let lengths = [Length::from(1.0), Length::from(2.0), Length::from(3.0)];
+        let variance: Area = stats::variance(&lengths).unwrap();
+        assert_eq!(variance, Area::from(2.0 / 3.0));
+        let std_dev: Length = variance.sqrt();
+        assert!((std_dev.value() - (2.0_f64 / 3.0).sqrt()).abs() < 1e-12);
+        assert_eq!(stats::variance::<{ Time::<f64>::UNIT }, f64>(&[]), None);
+    }
+}
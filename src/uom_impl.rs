@@ -0,0 +1,62 @@
+// Conversions from and to the `f64` SI quantities of `uom` for length, mass,
+// time, velocity, force, energy and power. Both crates store values in SI base
+// units. Other units have no conversion, so using one fails to compile.
+use crate::{Energy, Force, Length, Mass, Power, Time, Velocity};
+use uom::si::f64 as uom_si;
+
+macro_rules! bridge {
+    ($($quantity: ident => $module: ident :: $unit: ident),* $(,)?) => {
+        $(
+            impl From<$quantity> for uom_si::$quantity {
+                fn from(quantity: $quantity) -> Self {
+                    Self::new::<uom::si::$module::$unit>(quantity.to_base_f64())
+                }
+            }
+
+            impl From<uom_si::$quantity> for $quantity {
+                fn from(quantity: uom_si::$quantity) -> Self {
+                    Self::from_base_f64(quantity.get::<uom::si::$module::$unit>())
+                }
+            }
+        )*
+    };
+}
+
+bridge!(
+    Length => length::meter,
+    Mass => mass::kilogram,
+    Time => time::second,
+    Velocity => velocity::meter_per_second,
+    Force => force::newton,
+    Energy => energy::joule,
+    Power => power::watt,
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use uom::si::f64 as uom_si;
+    use uom::si::{energy, length, mass};
+
+    #[test]
+    fn round_trip() {
+        let length = uom_si::Length::from(Length::from(2.5));
+        assert_eq!(length.get::<length::meter>(), 2.5);
+        assert_eq!(length.get::<length::millimeter>(), 2500.0);
+        assert_eq!(Length::from(length), Length::from(2.5));
+
+        let mass = uom_si::Mass::new::<mass::gram>(1500.0);
+        assert_eq!(Mass::from(mass), Mass::from(1.5));
+
+        let energy = Energy::from(uom_si::Energy::new::<energy::kilojoule>(2.0));
+        assert_eq!(energy, Energy::from(2000.0));
+        assert_eq!(Energy::from(uom_si::Energy::from(energy)), energy);
+    }
+
+    #[test]
+    fn derived() {
+        let force = Mass::from(2.0) * Acceleration::from(3.0);
+        let work: uom_si::Energy = (force * Length::from(4.0)).into();
+        assert_eq!(Energy::from(work), Energy::from(24.0));
+    }
+}
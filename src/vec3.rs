@@ -0,0 +1,173 @@
+use crate::{Float, Quantity, Scalar, Unit};
+use core::ops::*;
+
+// Three dimensional vector whose components share a unit. Products between
+// vectors add the units of both sides, e.g. force dot displacement is energy.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Vec3<const UNIT: Unit, S = f64>(pub [Quantity<UNIT, S>; 3]);
+
+impl<const UNIT: Unit, S: Scalar> Vec3<UNIT, S> {
+    pub const fn new(x: Quantity<UNIT, S>, y: Quantity<UNIT, S>, z: Quantity<UNIT, S>) -> Self {
+        Self([x, y, z])
+    }
+
+    pub fn dot<const RHS: Unit>(self, rhs: Vec3<RHS, S>) -> Quantity<{ UNIT.add(RHS) }, S>
+    where
+        Quantity<{ UNIT.add(RHS) }, S>: Sized,
+    {
+        let [a, b, c] = self.0;
+        let [x, y, z] = rhs.0;
+        Quantity(a.0 * x.0 + b.0 * y.0 + c.0 * z.0)
+    }
+
+    pub fn cross<const RHS: Unit>(self, rhs: Vec3<RHS, S>) -> Vec3<{ UNIT.add(RHS) }, S>
+    where
+        Quantity<{ UNIT.add(RHS) }, S>: Sized,
+    {
+        let [a, b, c] = self.0;
+        let [x, y, z] = rhs.0;
+        Vec3([
+            Quantity(b.0 * z.0 - c.0 * y.0),
+            Quantity(c.0 * x.0 - a.0 * z.0),
+            Quantity(a.0 * y.0 - b.0 * x.0),
+        ])
+    }
+}
+
+impl<const UNIT: Unit, S: Float> Vec3<UNIT, S> {
+    // The square root of `self.dot(self)`. The unit of the dot product is the
+    // square of `UNIT`, whose root is `UNIT` again.
+    pub fn length(self) -> Quantity<UNIT, S> {
+        let [a, b, c] = self.0;
+        Quantity((a.0 * a.0 + b.0 * b.0 + c.0 * c.0).sqrt())
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> Add for Vec3<UNIT, S> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let [a, b, c] = self.0;
+        let [x, y, z] = rhs.0;
+        Self([a + x, b + y, c + z])
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> Sub for Vec3<UNIT, S> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let [a, b, c] = self.0;
+        let [x, y, z] = rhs.0;
+        Self([a - x, b - y, c - z])
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> Neg for Vec3<UNIT, S> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.map(Neg::neg))
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> Mul<S> for Vec3<UNIT, S> {
+    type Output = Self;
+
+    fn mul(self, rhs: S) -> Self::Output {
+        Self(self.0.map(|q| q * rhs))
+    }
+}
+
+impl<const UNIT: Unit, S: Scalar> Div<S> for Vec3<UNIT, S> {
+    type Output = Self;
+
+    fn div(self, rhs: S) -> Self::Output {
+        Self(self.0.map(|q| q / rhs))
+    }
+}
+
+impl<const LHS_UNIT: Unit, const RHS_UNIT: Unit, S: Scalar> Mul<Quantity<RHS_UNIT, S>>
+    for Vec3<LHS_UNIT, S>
+where
+    Quantity<{ LHS_UNIT.add(RHS_UNIT) }, S>: Sized,
+{
+    type Output = Vec3<{ LHS_UNIT.add(RHS_UNIT) }, S>;
+
+    fn mul(self, rhs: Quantity<RHS_UNIT, S>) -> Self::Output {
+        Vec3(self.0.map(|q| Quantity(q.0 * rhs.0)))
+    }
+}
+
+impl<const LHS_UNIT: Unit, const RHS_UNIT: Unit, S: Scalar> Div<Quantity<RHS_UNIT, S>>
+    for Vec3<LHS_UNIT, S>
+where
+    Quantity<{ LHS_UNIT.sub(RHS_UNIT) }, S>: Sized,
+{
+    type Output = Vec3<{ LHS_UNIT.sub(RHS_UNIT) }, S>;
+
+    fn div(self, rhs: Quantity<RHS_UNIT, S>) -> Self::Output {
+        Vec3(self.0.map(|q| Quantity(q.0 / rhs.0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn lengths(x: f64, y: f64, z: f64) -> Vec3<{ Length::<f64>::UNIT }> {
+        Vec3::new(Length::from(x), Length::from(y), Length::from(z))
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = lengths(1.0, 2.0, 3.0);
+        let b = lengths(4.0, 5.0, 6.0);
+        assert_eq!(a + b, lengths(5.0, 7.0, 9.0));
+        assert_eq!(b - a, lengths(3.0, 3.0, 3.0));
+        assert_eq!(-a, lengths(-1.0, -2.0, -3.0));
+        assert_eq!(a * 2.0, lengths(2.0, 4.0, 6.0));
+        assert_eq!(b / 2.0, lengths(2.0, 2.5, 3.0));
+    }
+
+    #[test]
+    fn dot() {
+        let a = lengths(1.0, 2.0, 3.0);
+        let b = lengths(4.0, -5.0, 6.0);
+        let area: Area = a.dot(b);
+        assert_eq!(area, Area::from(12.0));
+
+        let force = Vec3::new(Force::from(2.0), Force::from(0.0), Force::from(0.0));
+        let work: Energy = force.dot(a);
+        assert_eq!(work, Energy::from(2.0));
+    }
+
+    #[test]
+    fn cross() {
+        let x = lengths(1.0, 0.0, 0.0);
+        let y = lengths(0.0, 1.0, 0.0);
+        let z = Vec3::new(Area::from(0.0), Area::from(0.0), Area::from(1.0));
+        assert_eq!(x.cross(y), z);
+
+        let a = lengths(1.0, 2.0, 3.0);
+        let b = lengths(4.0, 5.0, 6.0);
+        let c = Vec3::new(Area::from(-3.0), Area::from(6.0), Area::from(-3.0));
+        assert_eq!(a.cross(b), c);
+    }
+
+    #[test]
+    fn length() {
+        assert_eq!(lengths(2.0, 3.0, 6.0).length(), Length::from(7.0));
+    }
+
+    #[test]
+    fn scale_by_quantity() {
+        let velocity = Vec3::new(
+            Velocity::from(1.0),
+            Velocity::from(2.0),
+            Velocity::from(3.0),
+        );
+        assert_eq!(velocity * Time::from(2.0), lengths(2.0, 4.0, 6.0));
+        assert_eq!(lengths(2.0, 4.0, 6.0) / Time::from(2.0), velocity);
+    }
+}
@@ -0,0 +1,7 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+use p3d_si::*;
+
+fn main() {
+    let _ = Length::from(1.0) == Time::from(1.0);
+}
@@ -0,0 +1,7 @@
+// Dimensional errors must be rejected at compile time. The expected compiler
+// output is stored next to each case, regenerate it with `TRYBUILD=overwrite`.
+#[test]
+fn compile_fail() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile-fail/*.rs");
+}